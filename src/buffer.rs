@@ -29,3 +29,33 @@ pub trait BufRef: PartialEq {
         suffix
     }
 }
+
+/// [ItemBuf] buffers are sequences of individual items: `T` for `[T]` and `char` for `str`
+pub trait ItemBuf: BufRef {
+    /// The type of individual items
+    type Item;
+
+    /// An owned accumulation of items, such as `Vec<T>` or `String`
+    type Accumulator: Default;
+
+    /// The first item and its length in buffer units, or `None` if empty
+    fn first_item(&self) -> Option<(Self::Item, usize)>;
+
+    /// Append all items of `self` to `acc`
+    fn append_to(&self, acc: &mut Self::Accumulator);
+
+    /// The length in buffer units of the longest prefix of items which satisfy `pred`
+    fn prefix_len<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        let mut len = 0;
+        while let Some((item, itemlen)) = self.drop_up_to(len).first_item() {
+            if !pred(item) {
+                break;
+            }
+            len += itemlen;
+        }
+        len
+    }
+}
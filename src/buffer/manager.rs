@@ -1,21 +1,35 @@
+use std::any::Any;
+
 use crate::error::ParseResult;
 use crate::parser::Outcome;
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 /// Manage the buffering necessary for driving [ParserCore] in an i/o agnostic manner
 #[derive(Debug)]
 pub struct BufferManager {
     buffer: Vec<u8>,
     rstart: usize,
+    offset: usize,
 }
 
 impl From<Vec<u8>> for BufferManager {
     fn from(buffer: Vec<u8>) -> Self {
-        BufferManager { buffer, rstart: 0 }
+        BufferManager {
+            buffer,
+            rstart: 0,
+            offset: 0,
+        }
     }
 }
 
 impl BufferManager {
+    /// The absolute stream position of the first retained byte
+    ///
+    /// This is the total number of bytes consumed by parsers so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
     /// Get a writable byte slice for inserting new data
     pub fn get_write_slice(&mut self) -> &mut [u8] {
         if self.rstart == self.buffer.len() {
@@ -41,11 +55,43 @@ impl BufferManager {
     /// rotate | kept | uninit                  |
     ///        +------+-------------------------+
     /// ```
+    ///
+    /// The parser is driven with [ParserCore::feed_with] and [ParserCore::finalize_with] so that it observes absolute stream offsets.
     pub fn process_write<P>(
         &mut self,
         parser: P,
         readcnt: usize,
     ) -> ParseResult<Outcome<P, P::Output>, P::Error>
+    where
+        P: ParserCore<[u8]> + std::fmt::Debug,
+        P::Output: std::fmt::Debug,
+        P::Error: std::fmt::Debug,
+    {
+        self.process_write_in(parser, readcnt, None)
+    }
+
+    /// Process newly inserted data as [BufferManager::process_write] does, giving the parser access to the user `state`
+    pub fn process_write_with_state<P, S>(
+        &mut self,
+        parser: P,
+        readcnt: usize,
+        state: &mut S,
+    ) -> ParseResult<Outcome<P, P::Output>, P::Error>
+    where
+        P: ParserCore<[u8]> + std::fmt::Debug,
+        P::Output: std::fmt::Debug,
+        P::Error: std::fmt::Debug,
+        S: Any,
+    {
+        self.process_write_in(parser, readcnt, Some(state))
+    }
+
+    pub(crate) fn process_write_in<P>(
+        &mut self,
+        parser: P,
+        readcnt: usize,
+        state: Option<&mut dyn Any>,
+    ) -> ParseResult<Outcome<P, P::Output>, P::Error>
     where
         P: ParserCore<[u8]> + std::fmt::Debug,
         P::Output: std::fmt::Debug,
@@ -56,16 +102,18 @@ impl BufferManager {
 
         let end = self.rstart + readcnt;
         let rslice = &self.buffer[..end];
+        let ctx = Context::from_parts(self.offset, state);
 
         if readcnt == 0 {
-            let optoutput = parser.finalize(rslice)?;
+            let optoutput = parser.finalize_with(ctx, rslice)?;
             let output = optoutput.ok_or(ExpectedMoreInput)?;
             Ok(Parsed(output))
         } else {
-            let Update { consumed, outcome } = parser.feed(rslice)?;
+            let Update { consumed, outcome } = parser.feed_with(ctx, rslice)?;
 
             self.buffer.rotate_left(consumed);
             self.rstart = end - consumed;
+            self.offset += consumed;
 
             Ok(outcome)
         }
@@ -1,4 +1,4 @@
-use crate::buffer::{BufRef, Buffer};
+use crate::buffer::{BufRef, Buffer, ItemBuf};
 
 // TODO: generalize to Vec<T>
 impl Buffer for Vec<u8> {}
@@ -16,3 +16,28 @@ where
         <[T]>::split_at(self, mid)
     }
 }
+
+impl<T> ItemBuf for [T]
+where
+    T: Clone + PartialEq,
+{
+    type Item = T;
+    type Accumulator = Vec<T>;
+
+    fn first_item(&self) -> Option<(Self::Item, usize)> {
+        self.first().map(|item| (item.clone(), 1))
+    }
+
+    fn append_to(&self, acc: &mut Self::Accumulator) {
+        acc.extend_from_slice(self);
+    }
+
+    fn prefix_len<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.iter()
+            .position(|item| !pred(item.clone()))
+            .unwrap_or(self.len())
+    }
+}
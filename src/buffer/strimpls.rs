@@ -1,4 +1,4 @@
-use crate::buffer::BufRef;
+use crate::buffer::{BufRef, ItemBuf};
 
 impl BufRef for str {
     fn len(&self) -> usize {
@@ -9,3 +9,25 @@ impl BufRef for str {
         <str>::split_at(self, mid)
     }
 }
+
+impl ItemBuf for str {
+    type Item = char;
+    type Accumulator = String;
+
+    fn first_item(&self) -> Option<(Self::Item, usize)> {
+        self.chars().next().map(|c| (c, c.len_utf8()))
+    }
+
+    fn append_to(&self, acc: &mut Self::Accumulator) {
+        acc.push_str(self);
+    }
+
+    fn prefix_len<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.char_indices()
+            .find(|&(_, c)| !pred(c))
+            .map_or(self.len(), |(ix, _)| ix)
+    }
+}
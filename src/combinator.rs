@@ -1,14 +1,36 @@
-//! Parsers which compose subparsers to express sequencing ([Then]), alternatives (`Or` (not yet implemented)), and other constructs
+//! Parsers which compose subparsers to express sequencing ([Then], [AndThen]), alternatives ([Or], [choice], [Permutation]), lookahead ([Peek], [Not]), and other constructs
+mod andthen;
 mod backtrack;
+mod choice;
+mod maperror;
 mod mapoutput;
+mod mapwithstate;
+mod not;
 mod optional;
 mod or;
+mod peek;
+mod permutation;
+mod recursive;
 mod repeated;
 mod then;
+mod withspan;
 
+pub use self::andthen::AndThen;
 pub use self::backtrack::Backtrack;
+pub use self::choice::choice;
+pub use self::maperror::MapError;
 pub use self::mapoutput::MapOutput;
+pub(crate) use self::mapwithstate::require_state;
+pub use self::mapwithstate::MapWithState;
+pub use self::not::{not, Not};
 pub use self::optional::Optional;
 pub use self::or::Or;
+pub use self::peek::{peek, Peek};
+pub use self::permutation::{
+    optional_field, permutation, required_field, OptionalField, Permutation, PermutationError,
+    PermutationField, PermutationFields, RequiredField,
+};
+pub use self::recursive::{lazy, recursive, Recursive};
 pub use self::repeated::Repeated;
 pub use self::then::Then;
+pub use self::withspan::WithSpan;
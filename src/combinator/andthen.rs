@@ -0,0 +1,144 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use either::Either;
+
+use crate::buffer::BufRef;
+use crate::combinator::then::{Chain, Continuation};
+use crate::error::{ParseResult, ParseResultUpdateExt};
+use crate::parser::{Context, ParserCore, Update};
+
+/// Parse `X`, then the parser `Y` which `F` builds from its output, yielding `Y`'s output
+///
+/// This supports grammars where earlier input determines how later input is parsed, such as length-prefixed framing.
+pub struct AndThen<X, F, Y, B>
+where
+    X: ParserCore<B>,
+    F: FnOnce(X::Output) -> Y,
+    Y: ParserCore<B>,
+    B: ?Sized,
+{
+    chain: Chain<X, Build<F, Y>, B>,
+}
+
+impl<X, F, Y, B> AndThen<X, F, Y, B>
+where
+    X: ParserCore<B>,
+    F: FnOnce(X::Output) -> Y,
+    Y: ParserCore<B>,
+    B: ?Sized,
+{
+    /// Create a parser for `x` followed by the parser built by `f`
+    pub fn new(x: X, f: F) -> Self {
+        AndThen {
+            chain: Chain::new(x, Build(f, PhantomData)),
+        }
+    }
+}
+
+impl<X, F, Y, B> Clone for AndThen<X, F, Y, B>
+where
+    X: Clone + ParserCore<B>,
+    F: Clone + FnOnce(X::Output) -> Y,
+    Y: Clone + ParserCore<B>,
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        AndThen {
+            chain: self.chain.clone(),
+        }
+    }
+}
+
+impl<X, F, Y, B> fmt::Debug for AndThen<X, F, Y, B>
+where
+    X: fmt::Debug + ParserCore<B>,
+    F: FnOnce(X::Output) -> Y,
+    Y: fmt::Debug + ParserCore<B>,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AndThen")
+            .field("chain", &self.chain)
+            .finish()
+    }
+}
+
+impl<X, F, Y, B> ParserCore<B> for AndThen<X, F, Y, B>
+where
+    X: ParserCore<B>,
+    F: FnOnce(X::Output) -> Y,
+    Y: ParserCore<B>,
+    B: ?Sized + BufRef,
+{
+    type Output = Y::Output;
+    type Error = Either<X::Error, Y::Error>;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.chain
+            .feed_with(ctx, buffer)
+            .map_next(|chain| AndThen { chain })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.chain.finalize_with(ctx, buffer)
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        self.chain.finalize_consumed(ctx, buffer)
+    }
+}
+
+/// The [Continuation] of [AndThen], which builds `Y` from the output of `X`
+struct Build<F, Y>(F, PhantomData<Y>);
+
+impl<F, Y> Clone for Build<F, Y>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Build(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F, Y> fmt::Debug for Build<F, Y> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("...")
+    }
+}
+
+impl<B, XO, F, Y> Continuation<B, XO> for Build<F, Y>
+where
+    B: ?Sized,
+    F: FnOnce(XO) -> Y,
+    Y: ParserCore<B>,
+{
+    type Parser = Y;
+
+    fn start(self, xout: XO) -> Y {
+        (self.0)(xout)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use std::convert::Infallible;
+
+use either::Either;
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, take, uint, NumberError};
+
+#[test_case("3:abcdef" => Ok(Update { consumed: 5, outcome: Parsed("abc".to_string()) }) ; "complete")]
+#[test_case("3:ab" => Ok(Update { consumed: 4, outcome: Next(()) }) ; "partial_body")]
+#[test_case("0:" => Ok(Update { consumed: 2, outcome: Parsed(String::new()) }) ; "empty_body")]
+#[test_case("3" => Ok(Update { consumed: 1, outcome: Next(()) }) ; "partial_prefix")]
+#[test_case(":" => Err(UnexpectedInput) ; "missing_prefix")]
+#[test_case("999999999999999999999:" => Err(Custom(Either::Left(NumberError::Overflow))) ; "prefix_error")]
+fn netstring(
+    input: &str,
+) -> ParseResult<Update<(), String>, Either<NumberError, Either<Infallible, Infallible>>> {
+    uint::<usize, _>()
+        .and_then(|len| literal(":").then(take(len)))
+        .feed(input)
+        .map(|up| up.map_next(|_| ()).map_output(|(_, body)| body))
+}
+
+#[test_case("abc" => Ok(Some("abc".to_string())) ; "exact")]
+#[test_case("ab" => Err(ExpectedMoreInput) ; "short")]
+fn netstring_finalize_body(
+    input: &str,
+) -> ParseResult<Option<String>, Either<NumberError, Either<Infallible, Infallible>>> {
+    let Update { consumed, outcome } = uint::<usize, _>()
+        .and_then(|len| literal(":").then(take(len)))
+        .feed("3:")
+        .unwrap();
+    assert_eq!(consumed, 2);
+
+    let Next(parser) = outcome else {
+        panic!("expected Next");
+    };
+    parser
+        .finalize(input)
+        .map(|optval| optval.map(|(_, body)| body))
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn utf8_counts_chars(bufsize: usize) {
+    use crate::parser::ByteParser;
+
+    let (body, _) = take(1)
+        .and_then(|digit: String| take(digit.parse().unwrap()))
+        .then(literal("!"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("2🌏é!".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(body, "🌏é");
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn peek_and_then_at_end(bufsize: usize) {
+    use crate::parser::ByteParser;
+    use crate::primitive::{literal_owned, take_while};
+
+    let word = take_while(char::is_alphabetic)
+        .peek()
+        .and_then(literal_owned::<str, String>)
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("abc".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(word, "abc");
+}
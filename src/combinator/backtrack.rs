@@ -1,9 +1,9 @@
 use crate::buffer::BufRef;
 use crate::error::ParseResult;
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 /// A [Backtrack] parser holds onto all of the input buffer until the inner parser completes
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Backtrack<P> {
     parser: P,
     offset: usize,
@@ -24,11 +24,23 @@ where
     type Error = P::Error;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::{Next, Parsed};
 
         let Backtrack { parser, offset } = self;
         let subbuf = buffer.drop_up_to(offset);
-        let Update { consumed, outcome } = parser.feed(subbuf)?;
+        let Update { consumed, outcome } = parser.feed_with(ctx.advance(offset), subbuf)?;
         match outcome {
             Next(parser) => Ok(Update {
                 consumed: 0,
@@ -44,7 +56,29 @@ where
         }
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
-        self.parser.finalize(buffer)
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let Backtrack { parser, offset } = self;
+
+        // The retained prefix of `buffer` has already been fed to `parser`:
+        parser.finalize_with(ctx.advance(offset), buffer.drop_up_to(offset))
+    }
+
+    fn finalize_consumed(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        let Backtrack { parser, offset } = self;
+
+        Ok(parser
+            .finalize_consumed(ctx.advance(offset), buffer.drop_up_to(offset))?
+            .map(|(consumed, output)| (offset + consumed, output)))
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,23 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::combinator::Backtrack;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ParserCore, Update};
+use crate::primitive::literal;
+
+#[test_case("Hel" => matches Err(ExpectedMoreInput) ; "retained_prefix")]
+#[test_case("" => matches Err(ExpectedMoreInput) ; "empty")]
+fn finalize_retained(input: &str) -> ParseResult<Option<&str>, Infallible> {
+    let p = Backtrack::from(literal("Hello"));
+    let Update { consumed, outcome } = p.feed(input)?;
+    // Backtrack retains everything its inner parser has consumed:
+    assert_eq!(consumed, 0);
+    match outcome {
+        Next(p) => p.finalize(input),
+        Parsed(output) => Ok(Some(output)),
+    }
+}
@@ -0,0 +1,30 @@
+use either::Either;
+
+use crate::buffer::BufRef;
+use crate::parser::{BoxedParser, ParserCore, PushParser};
+
+#[cfg(doc)]
+use crate::{combinator::Or, primitive::empty};
+
+/// Construct a boxed parser for the first of `alternatives` which matches, or else `otherwise`
+///
+/// The alternatives are tried in order as with [Or], so a custom error of an alternative is treated as a mismatch, while that of `otherwise` is an error of the choice. Pass [empty] as `otherwise` for a choice which fails when no alternative matches.
+pub fn choice<'a, B, O, X, I, P>(alternatives: I, otherwise: P) -> BoxedParser<'a, B, O, P::Error>
+where
+    B: ?Sized + BufRef + 'a,
+    O: 'a,
+    X: 'a,
+    I: IntoIterator<Item = BoxedParser<'a, B, O, X>>,
+    I::IntoIter: DoubleEndedIterator,
+    P: ParserCore<B, Output = O> + Clone + 'a,
+{
+    alternatives
+        .into_iter()
+        .rev()
+        .fold(otherwise.boxed(), |rest, alternative| {
+            alternative.or(rest).map_output(Either::into_inner).boxed()
+        })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,32 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::combinator::choice;
+use crate::error::ParseError::{Custom, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{BoxedParser, ParserCore, PushParser};
+use crate::primitive::{empty, literal, uint, NumberError};
+
+fn keywords<'a>() -> Vec<BoxedParser<'a, str, &'a str, Infallible>> {
+    ["let", "letter", "in"]
+        .into_iter()
+        .map(|keyword| literal(keyword).boxed())
+        .collect()
+}
+
+#[test_case("let" => Ok(Some("let")) ; "first")]
+#[test_case("letter" => Ok(Some("let")) ; "first_prefix")]
+#[test_case("in" => Ok(Some("in")) ; "last")]
+#[test_case("out" => Err(UnexpectedInput) ; "none")]
+fn first_match(input: &str) -> ParseResult<Option<&str>, Infallible> {
+    choice(keywords(), empty()).finalize(input)
+}
+
+#[test_case("42" => Ok(Some(42)) ; "alternative")]
+#[test_case("300" => Ok(Some(300)) ; "alternative_error")]
+#[test_case("70000" => Err(Custom(NumberError::Overflow)) ; "otherwise_error")]
+fn otherwise(input: &str) -> ParseResult<Option<u16>, NumberError> {
+    let alternatives = [uint::<u8, _>().map_output(u16::from).boxed()];
+    choice(alternatives, uint::<u16, _>()).finalize(input)
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::error::{ParseResult, ParseResultExt};
+use crate::parser::{Context, ParserCore, Update};
+
+/// Convert the custom error of parser `P` with fn `F`
+#[derive(Debug)]
+pub struct MapError<P, F, E, B>
+where
+    B: ?Sized,
+    P: ParserCore<B>,
+    F: FnOnce(P::Error) -> E,
+{
+    parser: P,
+    map: F,
+    phantom: PhantomData<(E, B)>,
+}
+
+impl<P, F, E, B> MapError<P, F, E, B>
+where
+    B: ?Sized,
+    P: ParserCore<B>,
+    F: FnOnce(P::Error) -> E,
+{
+    /// Construct a new `MapError`
+    pub fn new(parser: P, map: F) -> Self {
+        MapError {
+            parser,
+            map,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, F, E, B> Clone for MapError<P, F, E, B>
+where
+    B: ?Sized,
+    P: Clone + ParserCore<B>,
+    F: Clone + FnOnce(P::Error) -> E,
+{
+    fn clone(&self) -> Self {
+        MapError {
+            parser: self.parser.clone(),
+            map: self.map.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, F, E, B> ParserCore<B> for MapError<P, F, E, B>
+where
+    B: ?Sized,
+    P: ParserCore<B>,
+    F: FnOnce(P::Error) -> E,
+{
+    type Output = P::Output;
+    type Error = E;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        let MapError {
+            parser,
+            map,
+            phantom,
+        } = self;
+
+        match parser.feed_with(ctx, buffer) {
+            Ok(update) => Ok(update.map_next(|parser| MapError {
+                parser,
+                map,
+                phantom,
+            })),
+            Err(e) => Err(e.map_custom(map)),
+        }
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let MapError { parser, map, .. } = self;
+
+        parser.finalize_with(ctx, buffer).map_err_custom(map)
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        let MapError { parser, map, .. } = self;
+
+        parser.finalize_consumed(ctx, buffer).map_err_custom(map)
+    }
+}
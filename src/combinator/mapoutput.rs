@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
 
+use crate::buffer::BufRef;
 use crate::error::{ParseResult, ParseResultUpdateExt};
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 /// Convert the output of parser `P` with fn `F`
 #[derive(Debug)]
@@ -57,6 +58,18 @@ where
     type Error = P::Error;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::{Next, Parsed};
 
         let MapOutput {
@@ -65,7 +78,7 @@ where
             phantom,
         } = self;
 
-        parser.feed(buffer).map_outcome(|oc| match oc {
+        parser.feed_with(ctx, buffer).map_outcome(|oc| match oc {
             Next(parser) => Next(MapOutput {
                 parser,
                 map,
@@ -75,10 +88,31 @@ where
         })
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let MapOutput { parser, map, .. } = self;
+
+        parser
+            .finalize_with(ctx, buffer)
+            .map(|optval| optval.map(map))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
         let MapOutput { parser, map, .. } = self;
 
-        parser.finalize(buffer).map(|optval| optval.map(map))
+        Ok(parser
+            .finalize_consumed(ctx, buffer)?
+            .map(|(consumed, output)| (consumed, map(output))))
     }
 }
 
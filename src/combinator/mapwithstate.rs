@@ -0,0 +1,161 @@
+use std::any::{type_name, Any};
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::error::{ParseError, ParseResult};
+use crate::parser::{Context, ParserCore, Update};
+
+/// Convert the output of parser `P` with fn `F`, which also receives the per-parse user state `S`
+///
+/// `F` runs once `P` has parsed. Changes it makes to the state are not undone if an enclosing [Or](crate::combinator::Or) later backtracks.
+///
+/// Parsing fails with [ParseError::MissingState] if the [Context] has no user state of type `S`, as when driven by [ParserCore::feed] rather than a driver which accepts state.
+pub struct MapWithState<P, F, S, O, B>
+where
+    B: ?Sized,
+{
+    parser: P,
+    map: F,
+    state: PhantomData<fn(&mut S)>,
+    phantom: PhantomData<(O, B)>,
+}
+
+impl<P, F, S, O, B> MapWithState<P, F, S, O, B>
+where
+    B: ?Sized,
+    P: ParserCore<B>,
+    F: FnOnce(&mut S, P::Output) -> O,
+    S: Any,
+{
+    /// Construct a new `MapWithState`
+    pub fn new(parser: P, map: F) -> Self {
+        MapWithState {
+            parser,
+            map,
+            state: PhantomData,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, F, S, O, B> Clone for MapWithState<P, F, S, O, B>
+where
+    B: ?Sized,
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        MapWithState {
+            parser: self.parser.clone(),
+            map: self.map.clone(),
+            state: PhantomData,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, F, S, O, B> fmt::Debug for MapWithState<P, F, S, O, B>
+where
+    B: ?Sized,
+    P: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapWithState")
+            .field("parser", &self.parser)
+            .field("map", &"...")
+            .finish()
+    }
+}
+
+impl<P, F, S, O, B> ParserCore<B> for MapWithState<P, F, S, O, B>
+where
+    B: ?Sized,
+    P: ParserCore<B>,
+    F: FnOnce(&mut S, P::Output) -> O,
+    S: Any,
+{
+    type Output = O;
+    type Error = P::Error;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let MapWithState {
+            parser,
+            map,
+            state,
+            phantom,
+        } = self;
+
+        let Update { consumed, outcome } = parser.feed_with(ctx.reborrow(), buffer)?;
+
+        Ok(Update {
+            consumed,
+            outcome: match outcome {
+                Next(parser) => Next(MapWithState {
+                    parser,
+                    map,
+                    state,
+                    phantom,
+                }),
+                Parsed(output) => Parsed(map(require_state(&mut ctx)?, output)),
+            },
+        })
+    }
+
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let MapWithState { parser, map, .. } = self;
+
+        let Some(output) = parser.finalize_with(ctx.reborrow(), buffer)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(map(require_state(&mut ctx)?, output)))
+    }
+
+    fn finalize_consumed(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        let MapWithState { parser, map, .. } = self;
+
+        let Some((consumed, output)) = parser.finalize_consumed(ctx.reborrow(), buffer)? else {
+            return Ok(None);
+        };
+
+        Ok(Some((consumed, map(require_state(&mut ctx)?, output))))
+    }
+}
+
+/// Get the user state of type `S` from `ctx`, or else a [ParseError::MissingState] error
+pub(crate) fn require_state<'c, S, E>(ctx: &'c mut Context<'_>) -> ParseResult<&'c mut S, E>
+where
+    S: Any,
+{
+    ctx.state()
+        .ok_or(ParseError::MissingState(type_name::<S>()))
+}
+
+#[cfg(test)]
+mod tests;
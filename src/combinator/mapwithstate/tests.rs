@@ -0,0 +1,84 @@
+use test_case::test_case;
+
+use crate::error::ParseError::MissingState;
+use crate::parser::{ByteParser, ParserCore, PushParser};
+use crate::primitive::{literal, take};
+use crate::sequence::SequenceParser;
+
+fn intern(interner: &mut Vec<Vec<u8>>, word: Vec<u8>) -> usize {
+    interner.iter().position(|w| w == &word).unwrap_or_else(|| {
+        interner.push(word);
+        interner.len() - 1
+    })
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn interner(bufsize: usize) {
+    let mut interner: Vec<Vec<u8>> = vec![];
+    let ids = take(3)
+        .map_with_state(intern)
+        .repeated()
+        .collect::<Vec<_>>()
+        .parse_reader_with_bufsize_and_state(b"foobarfoobaz".as_slice(), bufsize, &mut interner)
+        .unwrap();
+
+    assert_eq!(ids, vec![0, 1, 0, 2]);
+    assert_eq!(
+        interner,
+        vec![b"foo".to_vec(), b"bar".to_vec(), b"baz".to_vec()]
+    );
+}
+
+#[test_case(1)]
+#[test_case(1<<14)]
+fn counter_across_then(bufsize: usize) {
+    let mut count = 0usize;
+    let (a, b) = literal(b"ab".as_slice())
+        .map_with_state(|n: &mut usize, _| {
+            *n += 1;
+            *n
+        })
+        .then(literal(b"c".as_slice()).map_with_state(|n: &mut usize, _| {
+            *n += 10;
+            *n
+        }))
+        .parse_reader_with_bufsize_and_state(b"abc".as_slice(), bufsize, &mut count)
+        .unwrap();
+
+    assert_eq!((a, b), (1, 11));
+    assert_eq!(count, 11);
+}
+
+#[test]
+fn state_via_boxed() {
+    let mut count = 0usize;
+    let n = literal(b"x".as_slice())
+        .map_with_state(|n: &mut usize, _| {
+            *n += 1;
+            *n
+        })
+        .boxed()
+        .parse_reader_with_state(b"x".as_slice(), &mut count)
+        .unwrap();
+
+    assert_eq!(n, 1);
+}
+
+#[test]
+fn missing_state() {
+    let result = literal("x").map_with_state(|n: &mut usize, _| *n).feed("x");
+
+    assert!(matches!(result, Err(MissingState("usize"))));
+}
+
+#[test]
+fn mismatched_state() {
+    let result = literal(b"x".as_slice())
+        .map_with_state(|n: &mut usize, _| *n)
+        .parse_reader_with_state(b"x".as_slice(), &mut "wrong");
+
+    assert!(matches!(result, Err(MissingState("usize"))));
+}
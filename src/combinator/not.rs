@@ -0,0 +1,86 @@
+use std::convert::Infallible;
+
+use crate::buffer::BufRef;
+use crate::combinator::Backtrack;
+use crate::error::ParseError::UnexpectedInput;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [Not] parser which succeeds without consuming input only when `parser` fails
+pub fn not<P>(parser: P) -> Not<P> {
+    Not::from(parser)
+}
+
+/// Negative lookahead: succeed without consuming input only if `P` fails to parse
+#[derive(Clone, Debug)]
+pub struct Not<P>(Backtrack<P>);
+
+impl<P> From<P> for Not<P> {
+    fn from(parser: P) -> Self {
+        Not(Backtrack::from(parser))
+    }
+}
+
+impl<B, P> ParserCore<B> for Not<P>
+where
+    B: ?Sized + BufRef,
+    P: ParserCore<B>,
+{
+    type Output = ();
+    type Error = Infallible;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.0.feed_with(ctx, buffer) {
+            Ok(Update {
+                outcome: Next(bt), ..
+            }) => Ok(Update {
+                consumed: 0,
+                outcome: Next(Not(bt)),
+            }),
+            Ok(Update {
+                outcome: Parsed(_), ..
+            }) => Err(UnexpectedInput),
+            Err(_) => Ok(Update {
+                consumed: 0,
+                outcome: Parsed(()),
+            }),
+        }
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        match self.0.finalize_with(ctx, buffer) {
+            Ok(Some(_)) => Err(UnexpectedInput),
+            _ => Ok(Some(())),
+        }
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        // Lookahead consumes no input:
+        Ok(self.finalize_with(ctx, buffer)?.map(|output| (0, output)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,59 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::UnexpectedInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, take_while};
+
+#[test_case(
+    "\""
+    => matches Err(UnexpectedInput)
+    ; "quote"
+)]
+#[test_case(
+    "x\""
+    => matches Ok(Update { consumed: 0, outcome: Parsed(()) })
+    ; "not_quote"
+)]
+#[test_case(
+    ""
+    => matches Ok(Update { consumed: 0, outcome: Next(_) })
+    ; "empty"
+)]
+fn not_quote(input: &str) -> ParseResult<Update<(), ()>, Infallible> {
+    literal("\"")
+        .not()
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(b"if x", true ; "keyword_boundary")]
+#[test_case(b"if_x", false ; "identifier_continues")]
+#[test_case(b"if", true ; "keyword_at_end")]
+fn keyword_boundary(input: &[u8], expected: bool) {
+    for bufsize in [1, 2, 1 << 14] {
+        let result = literal(b"if".as_slice())
+            .then(literal(b"_".as_slice()).not())
+            .parse_reader_with_bufsize::<_, Infallible>(input, bufsize);
+
+        assert_eq!(result.is_ok(), expected, "bufsize {bufsize}");
+    }
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn not_then_at_end(bufsize: usize) {
+    // The lookahead is still pending at end of input:
+    let ((), word) = literal("xyz")
+        .not()
+        .then(take_while(char::is_alphabetic))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("xy".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(word, "xy");
+}
@@ -3,10 +3,10 @@ use std::convert::Infallible;
 use crate::buffer::BufRef;
 use crate::combinator::Backtrack;
 use crate::error::{ParseResult, ParseResultUpdateExt};
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 /// Attempt to parse `P`, or else yield `None`
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Optional<P>(Backtrack<P>);
 
 impl<P> From<P> for Optional<P> {
@@ -24,11 +24,23 @@ where
     type Error = Infallible;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::Parsed;
 
         Ok(self
             .0
-            .feed(buffer)
+            .feed_with(ctx, buffer)
             .map_next(Optional)
             .map_output(Some)
             .unwrap_or(Update {
@@ -37,8 +49,25 @@ where
             }))
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
-        Ok(self.0.finalize(buffer).unwrap_or(None).map(Some))
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        // A failure to finalize the inner parser is a successful `None`:
+        Ok(Some(self.0.finalize_with(ctx, buffer).ok().flatten()))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        // A failure to finalize the inner parser is a successful `None` which consumes nothing:
+        Ok(Some(match self.0.finalize_consumed(ctx, buffer) {
+            Ok(Some((consumed, output))) => (consumed, Some(output)),
+            _ => (0, None),
+        }))
     }
 }
 
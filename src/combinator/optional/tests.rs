@@ -42,3 +42,15 @@ where
 {
     Literal::from(literal).optional().feed(input)
 }
+
+#[test_case("" => matches Ok(Some(None)) ; "empty")]
+#[test_case("Hel" => matches Ok(Some(None)) ; "str_hel_prefix_hello")]
+#[test_case("Hello" => matches Ok(Some(Some("Hello"))) ; "str_hello")]
+fn finalize_literal(input: &str) -> ParseResult<Option<Option<&str>>, Infallible> {
+    let Update { consumed, outcome } = Literal::from("Hello").optional().feed(input)?;
+    match outcome {
+        // The input retained by the backtracking inner parser is passed to `finalize`:
+        Next(p) => p.finalize(&input[consumed..]),
+        Parsed(output) => Ok(Some(output)),
+    }
+}
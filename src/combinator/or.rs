@@ -5,10 +5,10 @@ use either::Either::{self, Left, Right};
 use crate::buffer::BufRef;
 use crate::combinator::Backtrack;
 use crate::error::{ParseResult, ParseResultUpdateExt};
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 /// Parse either `X` or `Y` yielding one of their outputs
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Or<X, Y> {
     xbt: Option<Backtrack<X>>,
     y: Y,
@@ -28,35 +28,70 @@ impl<B, X, Y> ParserCore<B> for Or<X, Y>
 where
     X: ParserCore<B>,
     Y: ParserCore<B>,
-    B: BufRef,
+    B: ?Sized + BufRef,
 {
     type Output = Either<X::Output, Y::Output>;
     type Error = Y::Error;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         let Or { xbt, y } = self;
 
         if let Some(xbt) = xbt {
-            if let Ok(update) = xbt.feed(buffer) {
+            if let Ok(update) = xbt.feed_with(ctx.reborrow(), buffer) {
                 return Ok(update
                     .map_next(|xbt| Or { xbt: Some(xbt), y })
                     .map_output(Left));
             }
         }
 
-        y.feed(buffer)
+        y.feed_with(ctx, buffer)
             .map_next(|y| Or { xbt: None, y })
             .map_output(Right)
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
         let Or { xbt, y } = self;
 
         if let Some(xbt) = xbt {
-            if let Ok(Some(output)) = xbt.finalize(buffer) {
+            if let Ok(Some(output)) = xbt.finalize_with(ctx.reborrow(), buffer) {
                 return Ok(Some(Left(output)));
             }
         }
-        y.finalize(buffer).map(|optval| optval.map(Right))
+        y.finalize_with(ctx, buffer).map(|optval| optval.map(Right))
+    }
+
+    fn finalize_consumed(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        let Or { xbt, y } = self;
+
+        if let Some(xbt) = xbt {
+            if let Ok(Some((consumed, output))) = xbt.finalize_consumed(ctx.reborrow(), buffer) {
+                return Ok(Some((consumed, Left(output))));
+            }
+        }
+        Ok(y.finalize_consumed(ctx, buffer)?
+            .map(|(consumed, output)| (consumed, Right(output))))
     }
 }
+
+#[cfg(test)]
+mod tests;
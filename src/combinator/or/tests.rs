@@ -0,0 +1,20 @@
+use std::convert::Infallible;
+use std::ops::Range;
+
+use either::Either::{self, Left, Right};
+use test_case::test_case;
+
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, PushParser};
+use crate::primitive::literal;
+
+type Spanned<'a> = (Range<usize>, Either<&'a str, &'a str>);
+
+#[test_case("ab;" => Ok(Some((0..2, Right("ab")))) ; "fallback_prefix")]
+#[test_case("ab;c" => Ok(Some((0..4, Left("ab;c")))) ; "first")]
+fn finalize_span(input: &str) -> ParseResult<Option<Spanned<'_>>, Infallible> {
+    literal("ab;c")
+        .or(literal("ab"))
+        .with_span()
+        .finalize(input)
+}
@@ -0,0 +1,70 @@
+use crate::buffer::BufRef;
+use crate::combinator::Backtrack;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [Peek] parser which yields the output of `parser` without consuming input
+pub fn peek<P>(parser: P) -> Peek<P> {
+    Peek::from(parser)
+}
+
+/// Parse `P` as lookahead, yielding its output without consuming any input
+#[derive(Clone, Debug)]
+pub struct Peek<P>(Backtrack<P>);
+
+impl<P> From<P> for Peek<P> {
+    fn from(parser: P) -> Self {
+        Peek(Backtrack::from(parser))
+    }
+}
+
+impl<B, P> ParserCore<B> for Peek<P>
+where
+    B: ?Sized + BufRef,
+    P: ParserCore<B>,
+{
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        let Update { outcome, .. } = self.0.feed_with(ctx, buffer)?;
+
+        // [Backtrack] retains all input while pending, and we discard its consumption once parsed:
+        Ok(Update {
+            consumed: 0,
+            outcome: outcome.map_next(Peek),
+        })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.0.finalize_with(ctx, buffer)
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        // Lookahead consumes no input:
+        Ok(self.0.finalize_with(ctx, buffer)?.map(|output| (0, output)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
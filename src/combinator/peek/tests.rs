@@ -0,0 +1,60 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::UnexpectedInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, take_while};
+
+#[test_case(
+    "Hello World!"
+    => matches Ok(Update { consumed: 0, outcome: Parsed("Hello") })
+    ; "hello_world"
+)]
+#[test_case(
+    "Hell"
+    => matches Ok(Update { consumed: 0, outcome: Next(_) })
+    ; "hell"
+)]
+#[test_case(
+    "Goodbye"
+    => matches Err(UnexpectedInput)
+    ; "goodbye"
+)]
+fn peek_literal(input: &str) -> ParseResult<Update<(), &str>, Infallible> {
+    literal("Hello")
+        .peek()
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn peek_then_consume(bufsize: usize) {
+    let (peeked, consumed) = literal(b"foo".as_slice())
+        .peek()
+        .then(literal(b"foobar".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"foobar".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(peeked, b"foo");
+    assert_eq!(consumed, b"foobar");
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn peek_then_at_end(bufsize: usize) {
+    let (peeked, consumed) = take_while(char::is_alphabetic)
+        .peek()
+        .then(take_while(char::is_alphabetic))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("abc".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(peeked, "abc");
+    assert_eq!(consumed, "abc");
+}
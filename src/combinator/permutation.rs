@@ -0,0 +1,337 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::buffer::BufRef;
+use crate::combinator::choice;
+use crate::error::ParseError::{Custom, ExpectedMoreInput};
+use crate::error::{ParseResult, ParseResultExt};
+use crate::parser::{BoxedParser, Context, ParserCore, PushParser, Update};
+use crate::primitive::empty;
+
+/// Construct a [Permutation] which parses each of a tuple of fields, in any order
+///
+/// Each field is either a [required_field] or an [optional_field].
+pub fn permutation<'a, B, F>(fields: F) -> Permutation<'a, B, F>
+where
+    B: ?Sized + BufRef + 'a,
+    F: PermutationFields<'a, B>,
+{
+    Permutation {
+        alternatives: Rc::new(fields.alternatives()),
+        slots: F::Slots::default(),
+        attempt: None,
+        phantom: PhantomData,
+    }
+}
+
+/// Mark a field of a [Permutation] which must appear, yielding `P::Output`
+pub fn required_field<P>(parser: P) -> RequiredField<P> {
+    RequiredField(parser)
+}
+
+/// Mark a field of a [Permutation] which may be absent, yielding `Option<P::Output>`
+pub fn optional_field<P>(parser: P) -> OptionalField<P> {
+    OptionalField(parser)
+}
+
+/// A field of a [Permutation] which must appear, constructed with [required_field]
+#[derive(Clone, Debug)]
+pub struct RequiredField<P>(P);
+
+/// A field of a [Permutation] which may be absent, constructed with [optional_field]
+#[derive(Clone, Debug)]
+pub struct OptionalField<P>(P);
+
+/// A custom error from [Permutation], identifying a field by its index in the tuple
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum PermutationError {
+    /// A required field does not appear
+    #[error("missing required field {0}")]
+    Missing(usize),
+    /// A field appears more than once
+    #[error("duplicate field {0}")]
+    Duplicate(usize),
+}
+
+/// A field of a [Permutation]: a [RequiredField] or an [OptionalField]
+pub trait PermutationField<B>
+where
+    B: ?Sized,
+{
+    /// The parser of this field
+    type Parser: ParserCore<B> + Clone;
+    /// The output of this field in the [Permutation] output tuple
+    type Output;
+
+    /// Convert this field into its parser
+    fn into_parser(self) -> Self::Parser;
+
+    /// Yield the output for this field given its parsed value, if any, or `None` if it is missing
+    fn finish(value: Option<<Self::Parser as ParserCore<B>>::Output>) -> Option<Self::Output>;
+}
+
+impl<B, P> PermutationField<B> for RequiredField<P>
+where
+    B: ?Sized,
+    P: ParserCore<B> + Clone,
+{
+    type Parser = P;
+    type Output = P::Output;
+
+    fn into_parser(self) -> Self::Parser {
+        self.0
+    }
+
+    fn finish(value: Option<P::Output>) -> Option<Self::Output> {
+        value
+    }
+}
+
+impl<B, P> PermutationField<B> for OptionalField<P>
+where
+    B: ?Sized,
+    P: ParserCore<B> + Clone,
+{
+    type Parser = P;
+    type Output = Option<P::Output>;
+
+    fn into_parser(self) -> Self::Parser {
+        self.0
+    }
+
+    fn finish(value: Option<P::Output>) -> Option<Self::Output> {
+        Some(value)
+    }
+}
+
+/// Stores a parsed field value into its slot
+type Setter<'a, S> = Box<dyn FnOnce(&mut S) -> Result<(), PermutationError> + 'a>;
+
+/// Constructs a parser of one field which yields its [Setter]
+type Alternative<'a, B, S> = Rc<dyn Fn() -> BoxedParser<'a, B, Setter<'a, S>, ()> + 'a>;
+
+/// An alternation of every field which yields `None` if none match
+type Attempt<'a, B, S> = BoxedParser<'a, B, Option<Setter<'a, S>>, Infallible>;
+
+/// A tuple of up to 8 [PermutationField]s
+pub trait PermutationFields<'a, B>
+where
+    B: ?Sized,
+{
+    /// A tuple of the optional parsed value of each field
+    type Slots: Default;
+    /// A tuple of the output of each field
+    type Output;
+
+    /// Construct the parser of each field, in order
+    fn alternatives(self) -> Vec<Alternative<'a, B, Self::Slots>>;
+
+    /// Yield the output tuple, or the first missing required field
+    fn finish(slots: Self::Slots) -> Result<Self::Output, PermutationError>;
+}
+
+macro_rules! permutation_fields {
+    ( $( $field:ident $idx:tt ),+ ) => {
+        impl<'a, B, $( $field ),+> PermutationFields<'a, B> for ( $( $field, )+ )
+        where
+            B: ?Sized + 'a,
+            $(
+                $field: PermutationField<B>,
+                $field::Parser: 'a,
+                <$field::Parser as ParserCore<B>>::Output: 'a,
+            )+
+        {
+            type Slots = ( $( Option<<$field::Parser as ParserCore<B>>::Output>, )+ );
+            type Output = ( $( $field::Output, )+ );
+
+            fn alternatives(self) -> Vec<Alternative<'a, B, Self::Slots>> {
+                vec![ $( {
+                    let parser = self.$idx.into_parser();
+                    Rc::new(move || {
+                        parser
+                            .clone()
+                            .map_output(|value| {
+                                Box::new(move |slots: &mut Self::Slots| {
+                                    if slots.$idx.is_some() {
+                                        return Err(PermutationError::Duplicate($idx));
+                                    }
+                                    slots.$idx = Some(value);
+                                    Ok(())
+                                }) as Setter<'a, Self::Slots>
+                            })
+                            .map_error(drop)
+                            .boxed()
+                    }) as Alternative<'a, B, Self::Slots>
+                } ),+ ]
+            }
+
+            fn finish(slots: Self::Slots) -> Result<Self::Output, PermutationError> {
+                Ok(( $(
+                    $field::finish(slots.$idx).ok_or(PermutationError::Missing($idx))?,
+                )+ ))
+            }
+        }
+    };
+}
+
+permutation_fields!(P0 0);
+permutation_fields!(P0 0, P1 1);
+permutation_fields!(P0 0, P1 1, P2 2);
+permutation_fields!(P0 0, P1 1, P2 2, P3 3);
+permutation_fields!(P0 0, P1 1, P2 2, P3 3, P4 4);
+permutation_fields!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5);
+permutation_fields!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6);
+permutation_fields!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7);
+
+/// Parse a tuple of fields in any order, yielding a tuple of their outputs
+///
+/// At each position, the fields are tried in order as with [Or](crate::combinator::Or), and the permutation ends before the first input which no field matches. A custom error of a field parser is treated as a mismatch. The permutation then yields [PermutationError::Missing] if a required field has not appeared. A field which matches a second time yields [PermutationError::Duplicate].
+///
+/// Fields must include any delimiters between them, such as a trailing `;`. Cloning a started permutation clones the fields parsed so far, which requires their outputs to be [Clone].
+pub struct Permutation<'a, B, F>
+where
+    B: ?Sized,
+    F: PermutationFields<'a, B>,
+{
+    alternatives: Rc<Vec<Alternative<'a, B, F::Slots>>>,
+    slots: F::Slots,
+    attempt: Option<Attempt<'a, B, F::Slots>>,
+    phantom: PhantomData<fn() -> F>,
+}
+
+impl<'a, B, F> Permutation<'a, B, F>
+where
+    B: ?Sized + BufRef + 'a,
+    F: PermutationFields<'a, B>,
+    F::Slots: 'a,
+{
+    fn attempt(&self) -> Attempt<'a, B, F::Slots> {
+        choice(
+            self.alternatives.iter().map(|alternative| alternative()),
+            empty(),
+        )
+        .optional()
+        .boxed()
+    }
+
+    fn set(&mut self, setter: Setter<'a, F::Slots>) -> ParseResult<(), PermutationError> {
+        setter(&mut self.slots).map_err(Custom)
+    }
+}
+
+impl<'a, B, F> Clone for Permutation<'a, B, F>
+where
+    B: ?Sized,
+    F: PermutationFields<'a, B>,
+    F::Slots: Clone,
+{
+    fn clone(&self) -> Self {
+        Permutation {
+            alternatives: Rc::clone(&self.alternatives),
+            slots: self.slots.clone(),
+            attempt: self.attempt.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, B, F> fmt::Debug for Permutation<'a, B, F>
+where
+    B: ?Sized,
+    F: PermutationFields<'a, B>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Permutation")
+            .field("fields", &self.alternatives.len())
+            .field("started", &self.attempt.is_some())
+            .finish()
+    }
+}
+
+impl<'a, B, F> ParserCore<B> for Permutation<'a, B, F>
+where
+    B: ?Sized + BufRef + 'a,
+    F: PermutationFields<'a, B>,
+    F::Slots: 'a,
+{
+    type Output = F::Output;
+    type Error = PermutationError;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        mut self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let mut consumed = 0;
+        loop {
+            let attempt = self.attempt.take().unwrap_or_else(|| self.attempt());
+            let Update {
+                consumed: subconsumed,
+                outcome,
+            } = attempt
+                .feed_with(ctx.advance(consumed), buffer.drop_up_to(consumed))
+                .map_err_custom(|never| match never {})?;
+            consumed += subconsumed;
+
+            match outcome {
+                Next(attempt) => {
+                    self.attempt = Some(attempt);
+                    return Ok(Update {
+                        consumed,
+                        outcome: Next(self),
+                    });
+                }
+                Parsed(Some(setter)) => self.set(setter)?,
+                Parsed(None) => {
+                    return Ok(Update {
+                        consumed,
+                        outcome: Parsed(F::finish(self.slots)?),
+                    });
+                }
+            }
+        }
+    }
+
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let Update { consumed, outcome } = self.feed_with(ctx.reborrow(), buffer)?;
+        let mut permutation = match outcome {
+            Parsed(output) => return Ok(Some(output)),
+            Next(permutation) => permutation,
+        };
+
+        let attempt = permutation
+            .attempt
+            .take()
+            .expect("an unfinished permutation has an attempt");
+        let optsetter = attempt
+            .finalize_with(ctx.advance(consumed), buffer.drop_up_to(consumed))
+            .map_err_custom(|never| match never {})?
+            .ok_or(ExpectedMoreInput)?;
+        if let Some(setter) = optsetter {
+            permutation.set(setter)?;
+        }
+
+        F::finish(permutation.slots).map(Some).map_err(Custom)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,92 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::combinator::{optional_field, permutation, required_field, PermutationError};
+use crate::error::ParseError::Custom;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, uint};
+
+/// A field such as `x=1;`, yielding its value
+fn field(key: &'static str) -> impl ParserCore<str, Output = u32> + Clone {
+    literal(key)
+        .then(uint::<u32, _>())
+        .then(literal(";"))
+        .map_output(|((_, value), _)| value)
+}
+
+type Fields = (u32, u32, Option<u32>);
+
+fn finalize_fields(input: &str) -> ParseResult<Option<Fields>, PermutationError> {
+    permutation((
+        required_field(field("x=")),
+        required_field(field("y=")),
+        optional_field(field("z=")),
+    ))
+    .finalize(input)
+}
+
+#[test_case("x=1;y=2;z=3;" => Ok(Some((1, 2, Some(3)))) ; "in_order")]
+#[test_case("z=3;y=2;x=1;" => Ok(Some((1, 2, Some(3)))) ; "reversed")]
+#[test_case("y=2;z=3;x=1;" => Ok(Some((1, 2, Some(3)))) ; "rotated")]
+#[test_case("y=2;x=1;" => Ok(Some((1, 2, None))) ; "optional_absent")]
+#[test_case("x=1;z=3;" => Err(Custom(PermutationError::Missing(1))) ; "missing_required")]
+#[test_case("" => Err(Custom(PermutationError::Missing(0))) ; "empty")]
+#[test_case("x=1;y=2;x=4;" => Err(Custom(PermutationError::Duplicate(0))) ; "duplicate_required")]
+#[test_case("z=3;x=1;z=3;y=2;" => Err(Custom(PermutationError::Duplicate(2))) ; "duplicate_optional")]
+#[test_case("x=1;y=" => Err(Custom(PermutationError::Missing(1))) ; "incomplete_field")]
+fn finalize(input: &str) -> ParseResult<Option<Fields>, PermutationError> {
+    finalize_fields(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn split_feeds(bufsize: usize) {
+    let output = permutation((
+        required_field(field("x=")),
+        required_field(field("y=")),
+        optional_field(field("z=")),
+    ))
+    .into_utf8_parser()
+    .parse_reader_with_bufsize::<_, Infallible>(b"z=30;x=100;y=2;".as_slice(), bufsize)
+    .unwrap();
+
+    assert_eq!(output, (100, 2, Some(30)));
+}
+
+#[test]
+fn ends_before_other_input() {
+    let Update { consumed, outcome } =
+        permutation((required_field(field("x=")), optional_field(field("y="))))
+            .feed("y=2;x=1;w=0;")
+            .unwrap();
+
+    assert_eq!(consumed, 8);
+    assert!(matches!(outcome, Parsed((1, Some(2)))));
+}
+
+#[test]
+fn clone_is_unstarted() {
+    let fields = permutation((required_field(field("x=")), required_field(field("y="))));
+    let Update { outcome, .. } = fields.clone().feed("x=1;").unwrap();
+    assert!(matches!(outcome, Next(_)));
+
+    assert_eq!(fields.finalize("y=2;x=1;"), Ok(Some((1, 2))));
+}
+
+#[test]
+fn clone_started() {
+    let fields = permutation((required_field(field("x=")), required_field(field("y="))));
+    let Update { consumed, outcome } = fields.feed("y=2;x=").unwrap();
+    assert_eq!(consumed, 4);
+    let Next(started) = outcome else {
+        panic!("the x field is incomplete");
+    };
+
+    assert_eq!(started.clone().finalize("x=1;"), Ok(Some((1, 2))));
+    assert_eq!(started.finalize("x=3;"), Ok(Some((3, 2))));
+}
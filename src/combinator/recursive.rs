@@ -0,0 +1,141 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::buffer::BufRef;
+use crate::error::{ParseResult, ParseResultUpdateExt};
+use crate::parser::{BoxedParser, Context, ParserCore, Update};
+
+#[cfg(doc)]
+use crate::combinator::Repeated;
+
+/// Construct a [Recursive] parser for a self-referential grammar
+///
+/// `build` receives a handle to the parser being defined, which may be embedded anywhere in the grammar it returns, such as nested brackets in `[[][]]`. The grammar is built lazily upon the first feed, so a left-recursive grammar (where the handle is fed before any input is consumed) recurses without bound.
+pub fn recursive<'a, B, O, E, F, P>(build: F) -> Recursive<'a, B, O, E>
+where
+    B: ?Sized,
+    F: Fn(Recursive<'a, B, O, E>) -> P + 'a,
+    P: ParserCore<B, Output = O, Error = E> + Clone + 'a,
+{
+    Recursive {
+        build: Rc::new(move |handle| BoxedParser::new(build(handle))),
+        state: None,
+    }
+}
+
+/// Construct a [Recursive] parser which builds the parser `build` returns upon the first feed
+///
+/// This is [recursive] for a grammar which does not refer to itself, deferring its construction until it is fed.
+pub fn lazy<'a, B, O, E, F, P>(build: F) -> Recursive<'a, B, O, E>
+where
+    B: ?Sized,
+    F: Fn() -> P + 'a,
+    P: ParserCore<B, Output = O, Error = E> + Clone + 'a,
+{
+    recursive(move |_| build())
+}
+
+/// A self-referential parser constructed with [recursive]
+///
+/// Cloning a started parser clones its progress, so the grammar `build` returns must be [Clone], as combinators such as [Repeated] require.
+pub struct Recursive<'a, B, O, E>
+where
+    B: ?Sized,
+{
+    build: Rc<Builder<'a, B, O, E>>,
+    state: Option<BoxedParser<'a, B, O, E>>,
+}
+
+type Builder<'a, B, O, E> = dyn Fn(Recursive<'a, B, O, E>) -> BoxedParser<'a, B, O, E> + 'a;
+
+impl<B, O, E> Clone for Recursive<'_, B, O, E>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Recursive {
+            build: Rc::clone(&self.build),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<B, O, E> fmt::Debug for Recursive<'_, B, O, E>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recursive")
+            .field("started", &self.state.is_some())
+            .finish()
+    }
+}
+
+impl<'a, B, O, E> ParserCore<B> for Recursive<'a, B, O, E>
+where
+    B: ?Sized,
+{
+    type Output = O;
+    type Error = E;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        let Recursive { build, state } = self;
+        let state = state.unwrap_or_else(|| start(&build));
+
+        state.feed_with(ctx, buffer).map_next(|state| Recursive {
+            build,
+            state: Some(state),
+        })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let Recursive { build, state } = self;
+        let state = state.unwrap_or_else(|| start(&build));
+
+        state.finalize_with(ctx, buffer)
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        let Recursive { build, state } = self;
+        let state = state.unwrap_or_else(|| start(&build));
+
+        state.finalize_consumed(ctx, buffer)
+    }
+}
+
+/// Build the grammar on first use by giving it an unstarted handle to itself
+fn start<'a, B, O, E>(build: &Rc<Builder<'a, B, O, E>>) -> BoxedParser<'a, B, O, E>
+where
+    B: ?Sized,
+{
+    build(Recursive {
+        build: Rc::clone(build),
+        state: None,
+    })
+}
+
+#[cfg(test)]
+mod tests;
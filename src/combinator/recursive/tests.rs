@@ -0,0 +1,91 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::combinator::{lazy, recursive, Recursive};
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::Next;
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::literal;
+use crate::sequence::SequenceParser;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Nest(Vec<Nest>);
+
+type NestError = either::Either<either::Either<Infallible, Infallible>, Infallible>;
+
+fn nested<'a>() -> Recursive<'a, str, Nest, NestError> {
+    recursive(|nested| {
+        literal("[")
+            .then(nested.repeated().collect::<Vec<_>>())
+            .then(literal("]"))
+            .map_output(|((_, children), _)| Nest(children))
+    })
+}
+
+#[test_case("[]" => Ok(Nest(vec![])) ; "empty")]
+#[test_case(
+    "[[][[]]]"
+    => Ok(Nest(vec![Nest(vec![]), Nest(vec![Nest(vec![])])]))
+    ; "nested"
+)]
+#[test_case("[[]" => Err(ExpectedMoreInput) ; "unclosed")]
+#[test_case("[x]" => Err(UnexpectedInput) ; "unexpected")]
+fn parse_nested(input: &str) -> ParseResult<Nest, ()> {
+    let parse = |bufsize| {
+        nested()
+            .into_utf8_parser()
+            .parse_reader_with_bufsize::<_, Infallible>(input.as_bytes(), bufsize)
+            .map_err(|e| e.map_custom(|_| ()))
+    };
+
+    let result = parse(1 << 14);
+    for bufsize in [1, 3] {
+        assert_eq!(parse(bufsize), result, "bufsize {bufsize}");
+    }
+    result
+}
+
+#[test_case("" => Ok(vec![]) ; "empty")]
+#[test_case("abba" => Ok(vec!["a", "b", "b", "a"]) ; "alternatives")]
+#[test_case("abc" => Ok(vec!["a", "b"]) ; "stops_at_mismatch")]
+fn repeat_lazy(input: &str) -> ParseResult<Vec<&'static str>, ()> {
+    // `Or` is not `Clone`, so `lazy` makes it repeatable:
+    let parse = |bufsize| {
+        lazy(|| {
+            literal("a")
+                .or(literal("b"))
+                .map_output(either::Either::into_inner)
+        })
+        .repeated()
+        .collect::<Vec<_>>()
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(input.as_bytes(), bufsize)
+        .map_err(|e| e.map_custom(|_| ()))
+    };
+
+    let result = parse(1 << 14);
+    for bufsize in [1, 3] {
+        assert_eq!(parse(bufsize), result, "bufsize {bufsize}");
+    }
+    result
+}
+
+#[test]
+fn clone_started() {
+    let Update { consumed, outcome } = nested().feed("[[]").unwrap();
+    assert_eq!(consumed, 3);
+    let Next(started) = outcome else {
+        panic!("the outer bracket is open");
+    };
+
+    assert_eq!(
+        started.clone().finalize("]"),
+        Ok(Some(Nest(vec![Nest(vec![])])))
+    );
+    assert_eq!(
+        started.finalize("[]]"),
+        Ok(Some(Nest(vec![Nest(vec![]), Nest(vec![])])))
+    );
+}
@@ -3,13 +3,13 @@ use std::convert::Infallible;
 use crate::buffer::BufRef;
 use crate::combinator::Optional;
 use crate::error::{ParseResult, ParseResultUpdateExt};
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 #[cfg(doc)]
 use crate::sequence::SequenceParser;
 
 /// Parse `P` repeatedly as a [SequenceParser] with `Item = P::Output`
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Repeated<P>
 where
     P: Clone,
@@ -37,23 +37,54 @@ where
     type Error = Infallible;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::{Next, Parsed};
 
         let Repeated { template, current } = self;
 
-        current.feed(buffer).map_outcome(|oc| match oc {
+        current.feed_with(ctx, buffer).map_outcome(|oc| match oc {
             Next(current) => Next(Repeated { template, current }),
             Parsed(optout) => Parsed(emit_output(template)(optout)),
         })
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
         let Repeated { template, current } = self;
 
         current
-            .finalize(buffer)
+            .finalize_with(ctx, buffer)
             .map(|optoptout| optoptout.map(emit_output(template)))
     }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        let Repeated { template, current } = self;
+
+        current
+            .finalize_consumed(ctx, buffer)
+            .map(|optout| optout.map(|(consumed, optx)| (consumed, emit_output(template)(optx))))
+    }
 }
 
 fn emit_output<P, X>(template: P) -> impl FnOnce(Option<X>) -> Option<(Repeated<P>, X)>
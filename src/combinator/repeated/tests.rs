@@ -6,9 +6,13 @@ use crate::buffer::BufRef;
 use crate::combinator::Repeated;
 use crate::error::ParseResult;
 use crate::parser::Outcome::{Next, Parsed};
-use crate::parser::{ParserCore, PushParser, Update};
+use crate::parser::{Context, ParserCore, PushParser, Update};
 use crate::primitive::Literal;
 
+type RepLit<'a, B> = Repeated<Literal<'a, B>>;
+type RepLitResult<'a, B> =
+    ParseResult<Update<RepLit<'a, B>, Option<(RepLit<'a, B>, &'a B)>>, Infallible>;
+
 #[test_case(
     "Hello",
     "Hello World!"
@@ -33,15 +37,20 @@ use crate::primitive::Literal;
     => matches Ok(Update { consumed: 0, outcome: Next(_) })
     ; "str_hell_prefix_hello"
 )]
-fn parse_literal<'a, B>(
-    literal: &'a B,
-    input: &'a B,
-) -> ParseResult<
-    Update<Repeated<Literal<'a, B>>, Option<(Repeated<Literal<'a, B>>, &'a B)>>,
-    Infallible,
->
+fn parse_literal<'a, B>(literal: &'a B, input: &'a B) -> RepLitResult<'a, B>
 where
     B: ?Sized + BufRef,
 {
     Literal::from(literal).repeated().feed(input)
 }
+
+#[test_case("abab" => Ok(Some((2, Some("ab")))) ; "first_item")]
+#[test_case("ab" => Ok(Some((2, Some("ab")))) ; "last_item")]
+#[test_case("a" => Ok(Some((0, None))) ; "partial_item")]
+#[test_case("" => Ok(Some((0, None))) ; "end")]
+fn finalize_consumed(input: &str) -> ParseResult<Option<(usize, Option<&str>)>, Infallible> {
+    Literal::from("ab")
+        .repeated()
+        .finalize_consumed(Context::default(), input)
+        .map(|optout| optout.map(|(consumed, optx)| (consumed, optx.map(|(_, x)| x))))
+}
@@ -1,21 +1,24 @@
+use std::fmt;
 use std::marker::PhantomData;
 
 use either::Either;
 
+use crate::buffer::BufRef;
+use crate::error::ParseError::ExpectedMoreInput;
 use crate::error::{ParseResult, ParseResultExt, ParseResultUpdateExt};
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
+
+#[cfg(doc)]
+use crate::combinator::AndThen;
 
 /// Parse two subgrammars in sequence, `X` then `Y`, yielding both of their outputs
-#[derive(Debug)]
 pub struct Then<X, Y, B>
 where
     X: ParserCore<B>,
     Y: ParserCore<B>,
     B: ?Sized,
 {
-    xporv: Either<X, X::Output>,
-    y: Y,
-    ph: PhantomData<B>,
+    chain: Chain<X, Paired<Y>, B>,
 }
 
 impl<X, Y, B> Then<X, Y, B>
@@ -26,82 +29,342 @@ where
 {
     /// Create a sequential parser for `x` then `y`
     pub fn new(x: X, y: Y) -> Self {
-        use Either::Left;
+        Then {
+            chain: Chain::new(x, Paired(y)),
+        }
+    }
+}
 
+impl<X, Y, B> Clone for Then<X, Y, B>
+where
+    X: ParserCore<B> + Clone,
+    X::Output: Clone,
+    Y: ParserCore<B> + Clone,
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
         Then {
-            xporv: Left(x),
-            y,
-            ph: PhantomData,
+            chain: self.chain.clone(),
         }
     }
 }
 
+impl<X, Y, B> fmt::Debug for Then<X, Y, B>
+where
+    X: ParserCore<B> + fmt::Debug,
+    X::Output: fmt::Debug,
+    Y: ParserCore<B> + fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Then").field("chain", &self.chain).finish()
+    }
+}
+
 impl<X, Y, B> ParserCore<B> for Then<X, Y, B>
 where
     X: ParserCore<B>,
     Y: ParserCore<B>,
-    B: ?Sized,
+    B: ?Sized + BufRef,
 {
     type Output = (X::Output, Y::Output);
     type Error = Either<X::Error, Y::Error>;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.chain
+            .feed_with(ctx, buffer)
+            .map_next(|chain| Then { chain })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.chain.finalize_with(ctx, buffer)
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        self.chain.finalize_consumed(ctx, buffer)
+    }
+}
+
+/// Start the second parser of a [Chain] from the output of the first
+pub(super) trait Continuation<B, XO>
+where
+    B: ?Sized,
+{
+    /// The second parser, whose output is the output of the [Chain]
+    type Parser: ParserCore<B>;
+
+    /// Build the second parser once the first has parsed `xout`
+    fn start(self, xout: XO) -> Self::Parser;
+}
+
+/// The sequencing shared by [Then] and [AndThen]: parse `X`, then the parser `C` starts from its output
+pub(super) struct Chain<X, C, B>
+where
+    X: ParserCore<B>,
+    C: Continuation<B, X::Output>,
+    B: ?Sized,
+{
+    state: Either<(X, C), C::Parser>,
+    ph: PhantomData<B>,
+}
+
+impl<X, C, B> Chain<X, C, B>
+where
+    X: ParserCore<B>,
+    C: Continuation<B, X::Output>,
+    B: ?Sized,
+{
+    pub(super) fn new(x: X, cont: C) -> Self {
+        Chain {
+            state: Either::Left((x, cont)),
+            ph: PhantomData,
+        }
+    }
+}
+
+impl<X, C, B> Clone for Chain<X, C, B>
+where
+    X: ParserCore<B> + Clone,
+    C: Continuation<B, X::Output> + Clone,
+    C::Parser: Clone,
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Chain {
+            state: self.state.clone(),
+            ph: PhantomData,
+        }
+    }
+}
+
+impl<X, C, B> fmt::Debug for Chain<X, C, B>
+where
+    X: ParserCore<B> + fmt::Debug,
+    C: Continuation<B, X::Output> + fmt::Debug,
+    C::Parser: fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.state {
+            Either::Left((x, cont)) => f
+                .debug_struct("Chain")
+                .field("x", x)
+                .field("cont", cont)
+                .finish(),
+            Either::Right(y) => f.debug_struct("Chain").field("y", y).finish(),
+        }
+    }
+}
+
+impl<X, C, B> ParserCore<B> for Chain<X, C, B>
+where
+    X: ParserCore<B>,
+    C: Continuation<B, X::Output>,
+    B: ?Sized + BufRef,
+{
+    type Output = <C::Parser as ParserCore<B>>::Output;
+    type Error = Either<X::Error, <C::Parser as ParserCore<B>>::Error>;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::{Next, Parsed};
         use Either::{Left, Right};
 
-        let Then { xporv, y, ph } = self;
-
-        match xporv {
-            Left(xparser) => xparser
-                .feed(buffer)
-                .map_err_custom(Left)
-                .map_outcome(|outcome| match outcome {
-                    Next(xparser) => Next(Then {
-                        xporv: Left(xparser),
-                        y,
-                        ph,
-                    }),
-                    Parsed(xout) => Next(Then {
-                        xporv: Right(xout),
-                        y,
-                        ph,
-                    }),
-                }),
-            Right(xout) => {
-                y.feed(buffer)
-                    .map_err_custom(Right)
-                    .map_outcome(|outcome| match outcome {
-                        Next(y) => Next(Then {
-                            xporv: Right(xout),
-                            y,
+        let Chain { state, ph } = self;
+
+        match state {
+            Left((xparser, cont)) => {
+                let Update { consumed, outcome } = xparser
+                    .feed_with(ctx.reborrow(), buffer)
+                    .map_err_custom(Left)?;
+
+                match outcome {
+                    Next(xparser) => Ok(Update {
+                        consumed,
+                        outcome: Next(Chain {
+                            state: Left((xparser, cont)),
                             ph,
                         }),
-                        Parsed(yout) => Parsed((xout, yout)),
-                    })
+                    }),
+                    Parsed(xout) => {
+                        // Feed the remainder to `y` so it is not stranded at end of input:
+                        let intermediate = Chain {
+                            state: Right(cont.start(xout)),
+                            ph,
+                        };
+                        let subup = intermediate
+                            .feed_with(ctx.advance(consumed), buffer.drop_up_to(consumed))?;
+                        Ok(Update {
+                            consumed: consumed + subup.consumed,
+                            outcome: subup.outcome,
+                        })
+                    }
+                }
             }
+            Right(y) => y
+                .feed_with(ctx, buffer)
+                .map_err_custom(Right)
+                .map_next(|y| Chain {
+                    state: Right(y),
+                    ph,
+                }),
         }
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
-        use crate::error::ParseError::ExpectedMoreInput;
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(self
+            .finalize_consumed(ctx, buffer)?
+            .map(|(_, output)| output))
+    }
+
+    fn finalize_consumed(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
         use Either::{Left, Right};
 
-        let Then { xporv, y, .. } = self;
+        let (xconsumed, y) = match self.state {
+            Left((xparser, cont)) => {
+                let Some((xconsumed, xout)) = xparser
+                    .finalize_consumed(ctx.reborrow(), buffer)
+                    .map_err_custom(Left)?
+                else {
+                    // X finalized to None, so Y's result is irrelevant:
+                    return Ok(None);
+                };
 
-        let xoutopt = match xporv {
-            Left(xp) => xp.finalize(buffer).map_err_custom(Left)?,
-            Right(xout) => Some(xout),
+                (xconsumed, cont.start(xout))
+            }
+            Right(y) => (0, y),
         };
 
-        let youtopt = y.finalize(buffer).map_err_custom(Right)?;
-
-        match (xoutopt, youtopt) {
-            // Both finalize to a value, so we finalize to a value:
-            (Some(x), Some(y)) => Ok(Some((x, y))),
-            // X finalized to None, so Y's result is irrelevant:
-            (None, _) => Ok(None),
+        // Y finalizes on whatever input X left, such as all of it after lookahead:
+        match y
+            .finalize_consumed(ctx.advance(xconsumed), buffer.drop_up_to(xconsumed))
+            .map_err_custom(Right)?
+        {
+            Some((yconsumed, yout)) => Ok(Some((xconsumed + yconsumed, yout))),
             // Because X was a value, a lack of Y value is an error:
-            (Some(_), None) => Err(ExpectedMoreInput),
+            None => Err(ExpectedMoreInput),
         }
     }
 }
+
+/// The [Continuation] of [Then], which pairs the output of `X` with that of `Y`
+#[derive(Clone, Debug)]
+pub(super) struct Paired<Y>(Y);
+
+impl<B, XO, Y> Continuation<B, XO> for Paired<Y>
+where
+    B: ?Sized,
+    Y: ParserCore<B>,
+{
+    type Parser = PairedParser<XO, Y>;
+
+    fn start(self, xout: XO) -> Self::Parser {
+        PairedParser { xout, y: self.0 }
+    }
+}
+
+/// Parse `Y`, yielding its output after an already parsed `xout`
+#[derive(Clone, Debug)]
+pub(super) struct PairedParser<XO, Y> {
+    xout: XO,
+    y: Y,
+}
+
+impl<B, XO, Y> ParserCore<B> for PairedParser<XO, Y>
+where
+    B: ?Sized,
+    Y: ParserCore<B>,
+{
+    type Output = (XO, Y::Output);
+    type Error = Y::Error;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let PairedParser { xout, y } = self;
+
+        y.feed_with(ctx, buffer)
+            .map_outcome(|outcome| match outcome {
+                Next(y) => Next(PairedParser { xout, y }),
+                Parsed(yout) => Parsed((xout, yout)),
+            })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let PairedParser { xout, y } = self;
+
+        Ok(y.finalize_with(ctx, buffer)?.map(|yout| (xout, yout)))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        let PairedParser { xout, y } = self;
+
+        Ok(y.finalize_consumed(ctx, buffer)?
+            .map(|(consumed, yout)| (consumed, (xout, yout))))
+    }
+}
+
+#[cfg(test)]
+mod tests;
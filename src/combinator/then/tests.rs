@@ -0,0 +1,77 @@
+use std::convert::Infallible;
+
+use either::Either;
+use test_case::test_case;
+
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ParserCore, PushParser, Update};
+use crate::primitive::{literal, uint};
+
+type ThenResult<T> = ParseResult<T, Either<Infallible, Infallible>>;
+
+#[test_case("abcd" => matches Ok(Update { consumed: 4, outcome: Parsed(("ab", "cd")) }) ; "both_in_one_feed")]
+#[test_case("abc" => matches Ok(Update { consumed: 3, outcome: Next(()) }) ; "y_prefix")]
+#[test_case("ab" => matches Ok(Update { consumed: 2, outcome: Next(()) }) ; "x_only")]
+#[test_case("abx" => matches Err(_) ; "y_mismatch")]
+#[test_case("x" => matches Err(_) ; "x_mismatch")]
+fn feed_ab_then_cd(input: &str) -> ThenResult<Update<(), (&str, &str)>> {
+    literal("ab")
+        .then(literal("cd"))
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("a" => matches Ok(Some(("a", None))) ; "optional_y_absent")]
+#[test_case("ab" => matches Ok(Some(("a", Some("b")))) ; "optional_y_present")]
+fn finalize_a_then_optional_b(input: &str) -> ThenResult<Option<(&str, Option<&str>)>> {
+    let p = literal("a").then(literal("b").optional());
+    let Update { consumed, outcome } = p.feed(input)?;
+    assert_eq!(consumed, input.len());
+    match outcome {
+        Next(p) => p.finalize(""),
+        Parsed(output) => Ok(Some(output)),
+    }
+}
+
+#[test_case("a" => matches Err(ExpectedMoreInput) ; "y_missing")]
+#[test_case("" => matches Err(ExpectedMoreInput) ; "x_missing")]
+fn finalize_a_then_b(input: &str) -> ThenResult<Option<(&str, &str)>> {
+    let p = literal("a").then(literal("b"));
+    let Update { consumed, outcome } = p.feed(input)?;
+    assert_eq!(consumed, input.len());
+    match outcome {
+        Next(p) => p.finalize(""),
+        Parsed(output) => Ok(Some(output)),
+    }
+}
+
+fn feed_then_finalize<P>(parser: P, input: &str) -> ParseResult<Option<P::Output>, P::Error>
+where
+    P: ParserCore<str>,
+{
+    let Update { consumed, outcome } = parser.feed(input)?;
+    match outcome {
+        Next(parser) => parser.finalize(&input[consumed..]),
+        Parsed(output) => Ok(Some(output)),
+    }
+}
+
+#[test]
+fn or_fallback_prefix_then() {
+    let parser = literal("abc").or(literal("a")).then(literal("b"));
+    assert_eq!(
+        feed_then_finalize(parser, "ab"),
+        Ok(Some((Either::Right("a"), "b")))
+    );
+}
+
+#[test]
+fn or_fallback_number_then() {
+    let parser = literal("12;c").or(uint::<u32, _>()).then(literal(";"));
+    assert_eq!(
+        feed_then_finalize(parser, "12;"),
+        Ok(Some((Either::Right(12), ";")))
+    );
+}
@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+use crate::buffer::BufRef;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Yield the input range consumed by `P` alongside its output
+///
+/// The range is absolute when driven by [ParserCore::feed_with], as [BufferManager](crate::buffer::BufferManager) does, through combinators which forward the [Context]. When driven directly by [ParserCore::feed], the range is relative to the first input fed to this parser.
+#[derive(Clone, Debug)]
+pub struct WithSpan<P> {
+    parser: P,
+    start: Option<usize>,
+    next: usize,
+}
+
+impl<P> From<P> for WithSpan<P> {
+    fn from(parser: P) -> Self {
+        WithSpan {
+            parser,
+            start: None,
+            next: 0,
+        }
+    }
+}
+
+impl<B, P> ParserCore<B> for WithSpan<P>
+where
+    B: ?Sized + BufRef,
+    P: ParserCore<B>,
+{
+    type Output = (Range<usize>, P::Output);
+    type Error = P::Error;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        let offset = self.next;
+        self.feed_with(Context::new(offset), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let offset = self.next;
+        self.finalize_with(Context::new(offset), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let WithSpan { parser, start, .. } = self;
+        let offset = ctx.offset();
+        let start = start.unwrap_or(offset);
+
+        let Update { consumed, outcome } = parser.feed_with(ctx, buffer)?;
+        let end = offset + consumed;
+
+        Ok(Update {
+            consumed,
+            outcome: match outcome {
+                Next(parser) => Next(WithSpan {
+                    parser,
+                    start: Some(start),
+                    next: end,
+                }),
+                Parsed(output) => Parsed((start..end, output)),
+            },
+        })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(self
+            .finalize_consumed(ctx, buffer)?
+            .map(|(_, output)| output))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        let WithSpan { parser, start, .. } = self;
+        let offset = ctx.offset();
+        let start = start.unwrap_or(offset);
+
+        Ok(parser
+            .finalize_consumed(ctx, buffer)?
+            .map(|(consumed, output)| (consumed, (start..offset + consumed, output))))
+    }
+}
+
+#[cfg(test)]
+mod tests;
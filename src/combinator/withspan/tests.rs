@@ -0,0 +1,67 @@
+use std::convert::Infallible;
+use std::ops::Range;
+
+use test_case::test_case;
+
+use crate::parser::{ByteParser, PushParser, TextParser};
+use crate::primitive::literal;
+use crate::sequence::SequenceParser;
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn then_with_span(bufsize: usize) {
+    let ((_, (span, _)), (sspan, _)) = literal(b"foo".as_slice())
+        .then(literal(b"bar".as_slice()).with_span())
+        .then(literal(b"!".as_slice()).with_span())
+        .parse_reader_with_bufsize::<_, Infallible>(b"foobar!".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(span, 3..6);
+    assert_eq!(sspan, 6..7);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn repeated_with_span(bufsize: usize) {
+    let spans: Vec<Range<usize>> = literal(b"ab".as_slice())
+        .with_span()
+        .repeated()
+        .collect::<Vec<_>>()
+        .parse_reader_with_bufsize::<_, Infallible>(b"ababab".as_slice(), bufsize)
+        .unwrap()
+        .into_iter()
+        .map(|(span, _)| span)
+        .collect();
+
+    assert_eq!(spans, vec![0..2, 2..4, 4..6]);
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn or_backtrack_with_span(bufsize: usize) {
+    let output = literal(b"ab".as_slice())
+        .then(literal(b"x".as_slice()))
+        .or(literal(b"a".as_slice()).then(literal(b"bc".as_slice()).with_span()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"abc".as_slice(), bufsize)
+        .unwrap();
+
+    let (_, (span, _)) = output.right().unwrap();
+    assert_eq!(span, 1..3);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn utf8_with_span_counts_bytes(bufsize: usize) {
+    let (_, (span, _)) = literal("🌏")
+        .then(literal("!").with_span())
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("🌏!".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(span, 4..5);
+}
@@ -9,6 +9,10 @@ pub enum ParseError<E> {
     #[error("expected more input")]
     ExpectedMoreInput,
 
+    /// The parser requires user state of the named type, which the driver did not supply
+    #[error("parser requires user state of type {0}; drive it with a *_with_state method")]
+    MissingState(&'static str),
+
     /// The parser encountered a custom error
     #[error(transparent)]
     Custom(#[from] E),
@@ -27,6 +31,7 @@ impl<E> ParseError<E> {
         match self {
             UnexpectedInput => UnexpectedInput,
             ExpectedMoreInput => ExpectedMoreInput,
+            MissingState(name) => MissingState(name),
             Custom(e) => Custom(f(e)),
         }
     }
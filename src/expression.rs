@@ -0,0 +1,423 @@
+//! Operator-precedence expressions via [Expression], a streaming Pratt parser
+//!
+//! An [Expression] is built from an atom parser, such as a number or a parenthesized subexpression via [recursive](crate::combinator::recursive), and prefix, infix, and postfix operator parsers, each with a precedence and a callback which builds the output tree. Higher precedences bind tighter. Infix operators are left or right [Associativity]; a prefix operator binds tighter than a postfix or left-associative infix operator of the same precedence, and looser than a right-associative one.
+//!
+//! Operators are tried in the order they are registered, so an operator which is a prefix of another, such as `*` of `**`, must be registered after it. Each operator parser must consume input, and whitespace may be skipped with [lexeme](crate::primitive::text::lexeme).
+use std::convert::Infallible;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::buffer::BufRef;
+use crate::combinator::choice;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::{ParseResult, ParseResultExt};
+use crate::parser::{BoxedParser, Context, ParserCore, PushParser, Update};
+use crate::primitive::empty;
+
+/// The grouping of a sequence of infix operators of the same precedence
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Associativity {
+    /// `a - b - c` parses as `(a - b) - c`
+    Left,
+    /// `a ^ b ^ c` parses as `a ^ (b ^ c)`
+    Right,
+}
+
+type Factory<'a, B, O, E> = Rc<dyn Fn() -> BoxedParser<'a, B, O, E> + 'a>;
+type Unary<'a, T> = Rc<dyn Fn(T) -> T + 'a>;
+type Binary<'a, T> = Rc<dyn Fn(T, T) -> T + 'a>;
+
+/// The output of a parser in operand position
+enum Operand<'a, T> {
+    Prefix(Unary<'a, T>, usize),
+    Atom(T),
+}
+
+/// The output of a parser in operator position, with left and right binding powers
+enum Operator<'a, T> {
+    Postfix(Unary<'a, T>, usize),
+    Infix(Binary<'a, T>, usize, usize),
+}
+
+/// An operator awaiting its right operand, with its right binding power
+enum Frame<'a, T> {
+    Prefix(Unary<'a, T>, usize),
+    Infix(T, Binary<'a, T>, usize),
+}
+
+impl<T> Clone for Frame<'_, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Frame::Prefix(apply, bp) => Frame::Prefix(Rc::clone(apply), *bp),
+            Frame::Infix(lhs, apply, bp) => Frame::Infix(lhs.clone(), Rc::clone(apply), *bp),
+        }
+    }
+}
+
+impl<T> Frame<'_, T> {
+    fn binding_power(&self) -> usize {
+        match self {
+            Frame::Prefix(_, bp) | Frame::Infix(_, _, bp) => *bp,
+        }
+    }
+
+    fn apply(self, rhs: T) -> T {
+        match self {
+            Frame::Prefix(apply, _) => apply(rhs),
+            Frame::Infix(lhs, apply, _) => apply(lhs, rhs),
+        }
+    }
+}
+
+enum Mode<'a, B, T, E>
+where
+    B: ?Sized,
+{
+    Operand(BoxedParser<'a, B, Operand<'a, T>, E>),
+    Operator(T, BoxedParser<'a, B, Option<Operator<'a, T>>, Infallible>),
+}
+
+impl<B, T, E> Clone for Mode<'_, B, T, E>
+where
+    B: ?Sized,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Mode::Operand(parser) => Mode::Operand(parser.clone()),
+            Mode::Operator(lhs, parser) => Mode::Operator(lhs.clone(), parser.clone()),
+        }
+    }
+}
+
+struct Table<'a, B, T, E>
+where
+    B: ?Sized,
+{
+    atom: Factory<'a, B, T, E>,
+    prefix: Vec<Factory<'a, B, Operand<'a, T>, ()>>,
+    operators: Vec<Factory<'a, B, Operator<'a, T>, ()>>,
+}
+
+impl<B, T, E> Clone for Table<'_, B, T, E>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Table {
+            atom: Rc::clone(&self.atom),
+            prefix: self.prefix.clone(),
+            operators: self.operators.clone(),
+        }
+    }
+}
+
+impl<'a, B, T, E> Table<'a, B, T, E>
+where
+    B: ?Sized + BufRef + 'a,
+    T: 'a,
+    E: 'a,
+{
+    fn operand(&self) -> BoxedParser<'a, B, Operand<'a, T>, E> {
+        let atom = (self.atom)().map_output(Operand::Atom);
+        choice(self.prefix.iter().map(|prefix| prefix()), atom)
+    }
+
+    fn operator(&self) -> BoxedParser<'a, B, Option<Operator<'a, T>>, Infallible> {
+        choice(self.operators.iter().map(|operator| operator()), empty())
+            .optional()
+            .boxed()
+    }
+}
+
+/// A streaming Pratt parser for operator-precedence expressions, yielding the tree built by its callbacks
+///
+/// An [Expression] may be used within a [recursive](crate::combinator::recursive) atom or repeated. Cloning a started expression clones its pending operands, which requires `T: Clone`, as do the outputs of operator parsers. Parsing an expression ends before the first input which is neither an operator nor an operand where one is expected. A custom error of the atom parser is an error of the expression, while an operator which fails to parse ends the expression.
+pub struct Expression<'a, B, T, E>
+where
+    B: ?Sized,
+{
+    table: Rc<Table<'a, B, T, E>>,
+    stack: Vec<Frame<'a, T>>,
+    mode: Option<Mode<'a, B, T, E>>,
+}
+
+impl<'a, B, T, E> Expression<'a, B, T, E>
+where
+    B: ?Sized + BufRef + 'a,
+    T: 'a,
+    E: 'a,
+{
+    /// Construct an [Expression] of operands parsed by `atom`, with no operators
+    pub fn new<P>(atom: P) -> Self
+    where
+        P: ParserCore<B, Output = T, Error = E> + Clone + 'a,
+    {
+        Expression {
+            table: Rc::new(Table {
+                atom: Rc::new(move || atom.clone().boxed()),
+                prefix: vec![],
+                operators: vec![],
+            }),
+            stack: vec![],
+            mode: None,
+        }
+    }
+
+    /// Register a prefix operator parsed by `op`, where `build(op_output, operand)` builds its output
+    pub fn prefix<P, F>(mut self, op: P, precedence: usize, build: F) -> Self
+    where
+        P: ParserCore<B> + Clone + 'a,
+        P::Output: Clone + 'a,
+        F: Fn(P::Output, T) -> T + 'a,
+    {
+        let build = Rc::new(build);
+        let bp = 2 * precedence + 1;
+        Rc::make_mut(&mut self.table).prefix.push(Rc::new(move || {
+            let build = Rc::clone(&build);
+            op.clone()
+                .map_output(move |o| Operand::Prefix(Rc::new(move |x| build(o.clone(), x)), bp))
+                .map_error(drop)
+                .boxed()
+        }));
+        self
+    }
+
+    /// Register an infix operator parsed by `op`, where `build(lhs, op_output, rhs)` builds its output
+    pub fn infix<P, F>(
+        mut self,
+        op: P,
+        precedence: usize,
+        associativity: Associativity,
+        build: F,
+    ) -> Self
+    where
+        P: ParserCore<B> + Clone + 'a,
+        P::Output: Clone + 'a,
+        F: Fn(T, P::Output, T) -> T + 'a,
+    {
+        let build = Rc::new(build);
+        let (lbp, rbp) = match associativity {
+            Associativity::Left => (2 * precedence, 2 * precedence + 1),
+            Associativity::Right => (2 * precedence + 1, 2 * precedence),
+        };
+        Rc::make_mut(&mut self.table)
+            .operators
+            .push(Rc::new(move || {
+                let build = Rc::clone(&build);
+                op.clone()
+                    .map_output(move |o| {
+                        Operator::Infix(Rc::new(move |l, r| build(l, o.clone(), r)), lbp, rbp)
+                    })
+                    .map_error(drop)
+                    .boxed()
+            }));
+        self
+    }
+
+    /// Register a postfix operator parsed by `op`, where `build(operand, op_output)` builds its output
+    pub fn postfix<P, F>(mut self, op: P, precedence: usize, build: F) -> Self
+    where
+        P: ParserCore<B> + Clone + 'a,
+        P::Output: Clone + 'a,
+        F: Fn(T, P::Output) -> T + 'a,
+    {
+        let build = Rc::new(build);
+        let bp = 2 * precedence;
+        Rc::make_mut(&mut self.table)
+            .operators
+            .push(Rc::new(move || {
+                let build = Rc::clone(&build);
+                op.clone()
+                    .map_output(move |o| {
+                        Operator::Postfix(Rc::new(move |x| build(x, o.clone())), bp)
+                    })
+                    .map_error(drop)
+                    .boxed()
+            }));
+        self
+    }
+
+    /// Apply each pending operator which binds tighter than an operator with left binding power `bp`
+    fn reduce(&mut self, mut operand: T, bp: usize) -> T {
+        while self
+            .stack
+            .last()
+            .is_some_and(|frame| bp < frame.binding_power())
+        {
+            let frame = self.stack.pop().unwrap();
+            operand = frame.apply(operand);
+        }
+        operand
+    }
+
+    /// Apply every pending operator at the end of the expression
+    fn reduce_all(&mut self, operand: T) -> T {
+        self.stack
+            .drain(..)
+            .rev()
+            .fold(operand, |operand, frame| frame.apply(operand))
+    }
+
+    fn apply_operator(&mut self, lhs: T, operator: Operator<'a, T>) {
+        match operator {
+            Operator::Postfix(apply, lbp) => {
+                let operand = self.reduce(lhs, lbp);
+                self.mode = Some(Mode::Operator(apply(operand), self.table.operator()));
+            }
+            Operator::Infix(apply, lbp, rbp) => {
+                let lhs = self.reduce(lhs, lbp);
+                self.stack.push(Frame::Infix(lhs, apply, rbp));
+                self.mode = Some(Mode::Operand(self.table.operand()));
+            }
+        }
+    }
+}
+
+impl<B, T, E> Clone for Expression<'_, B, T, E>
+where
+    B: ?Sized,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Expression {
+            table: Rc::clone(&self.table),
+            stack: self.stack.clone(),
+            mode: self.mode.clone(),
+        }
+    }
+}
+
+impl<B, T, E> fmt::Debug for Expression<'_, B, T, E>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Expression")
+            .field("pending", &self.stack.len())
+            .field("started", &self.mode.is_some())
+            .finish()
+    }
+}
+
+impl<'a, B, T, E> ParserCore<B> for Expression<'a, B, T, E>
+where
+    B: ?Sized + BufRef + 'a,
+    T: 'a,
+    E: 'a,
+{
+    type Output = T;
+    type Error = E;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        mut self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let mut consumed = 0;
+        loop {
+            let rest = buffer.drop_up_to(consumed);
+            let mode = self
+                .mode
+                .take()
+                .unwrap_or_else(|| Mode::Operand(self.table.operand()));
+
+            match mode {
+                Mode::Operand(parser) => {
+                    let update = parser.feed_with(ctx.advance(consumed), rest)?;
+                    consumed += update.consumed;
+                    match update.outcome {
+                        Next(parser) => self.mode = Some(Mode::Operand(parser)),
+                        Parsed(Operand::Prefix(apply, bp)) => {
+                            self.stack.push(Frame::Prefix(apply, bp));
+                            continue;
+                        }
+                        Parsed(Operand::Atom(operand)) => {
+                            self.mode = Some(Mode::Operator(operand, self.table.operator()));
+                            continue;
+                        }
+                    }
+                }
+                Mode::Operator(lhs, parser) => {
+                    let update = parser
+                        .feed_with(ctx.advance(consumed), rest)
+                        .map_err_custom(|never| match never {})?;
+                    consumed += update.consumed;
+                    match update.outcome {
+                        Next(parser) => self.mode = Some(Mode::Operator(lhs, parser)),
+                        Parsed(Some(operator)) => {
+                            self.apply_operator(lhs, operator);
+                            continue;
+                        }
+                        Parsed(None) => {
+                            return Ok(Update {
+                                consumed,
+                                outcome: Parsed(self.reduce_all(lhs)),
+                            });
+                        }
+                    }
+                }
+            }
+
+            return Ok(Update {
+                consumed,
+                outcome: Next(self),
+            });
+        }
+    }
+
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let Update { consumed, outcome } = self.feed_with(ctx.reborrow(), buffer)?;
+        let mut expr = match outcome {
+            Parsed(output) => return Ok(Some(output)),
+            Next(expr) => expr,
+        };
+        let ctx = ctx.advance(consumed);
+        let rest = buffer.drop_up_to(consumed);
+
+        match expr.mode.take() {
+            Some(Mode::Operator(lhs, parser)) => {
+                let operator = parser
+                    .finalize_with(ctx, rest)
+                    .map_err_custom(|never| match never {})?
+                    .flatten();
+                match operator {
+                    Some(Operator::Postfix(apply, lbp)) => {
+                        let operand = expr.reduce(lhs, lbp);
+                        Ok(Some(expr.reduce_all(apply(operand))))
+                    }
+                    Some(Operator::Infix(..)) => Err(ExpectedMoreInput),
+                    None => Ok(Some(expr.reduce_all(lhs))),
+                }
+            }
+            Some(Mode::Operand(parser)) => match parser.finalize_with(ctx, rest)? {
+                Some(Operand::Atom(operand)) => Ok(Some(expr.reduce_all(operand))),
+                None if expr.stack.is_empty() => Ok(None),
+                _ => Err(ExpectedMoreInput),
+            },
+            None => unreachable!("feeding always starts an expression"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
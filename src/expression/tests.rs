@@ -0,0 +1,139 @@
+use std::convert::Infallible;
+
+use either::Either;
+use test_case::test_case;
+
+use crate::combinator::{recursive, Recursive};
+use crate::error::ParseError::{self, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::expression::Associativity::{Left, Right};
+use crate::expression::Expression;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::text::lexeme;
+use crate::primitive::{literal, uint, NumberError};
+
+/// Arithmetic over integers, yielding s-expressions
+fn arithmetic<'a>() -> Recursive<'a, str, String, NumberError> {
+    recursive(|expr| {
+        let parens = recursive(move |_| {
+            literal("(")
+                .then(expr.clone())
+                .then(literal(")"))
+                .map_output(|((_, inner), _)| inner)
+                .map_error(|e| e.left_or_else(|never| match never {}))
+                .map_error(|e| e.right_or_else(|never| match never {}))
+        });
+        let number = uint::<u64, _>().map_output(|n| n.to_string());
+
+        Expression::new(recursive(move |_| {
+            parens
+                .clone()
+                .or(number.clone())
+                .map_output(Either::into_inner)
+        }))
+        .infix(literal("+"), 1, Left, binary)
+        .infix(literal("-"), 1, Left, binary)
+        .infix(literal("*"), 2, Left, binary)
+        .prefix(literal("-"), 3, |op, x| format!("({op} {x})"))
+        .infix(literal("^"), 4, Right, binary)
+        .postfix(literal("!"), 5, |x, op| format!("({op} {x})"))
+    })
+}
+
+fn binary(lhs: String, op: &str, rhs: String) -> String {
+    format!("({op} {lhs} {rhs})")
+}
+
+#[test_case("7" => Ok(Some("7".to_string())) ; "atom")]
+#[test_case("1+2*3" => Ok(Some("(+ 1 (* 2 3))".to_string())) ; "precedence")]
+#[test_case("1*2+3" => Ok(Some("(+ (* 1 2) 3)".to_string())) ; "precedence_first")]
+#[test_case("1-2-3" => Ok(Some("(- (- 1 2) 3)".to_string())) ; "left_associative")]
+#[test_case("2^3^2" => Ok(Some("(^ 2 (^ 3 2))".to_string())) ; "right_associative")]
+#[test_case("-1*2" => Ok(Some("(* (- 1) 2)".to_string())) ; "prefix_tighter")]
+#[test_case("-2^2" => Ok(Some("(- (^ 2 2))".to_string())) ; "prefix_looser")]
+#[test_case("--1" => Ok(Some("(- (- 1))".to_string())) ; "nested_prefix")]
+#[test_case("1+2!" => Ok(Some("(+ 1 (! 2))".to_string())) ; "postfix")]
+#[test_case("-2!" => Ok(Some("(- (! 2))".to_string())) ; "postfix_tighter_than_prefix")]
+#[test_case("3!!" => Ok(Some("(! (! 3))".to_string())) ; "nested_postfix")]
+#[test_case("(1+2)*3" => Ok(Some("(* (+ 1 2) 3)".to_string())) ; "parentheses")]
+#[test_case("2*(3-(4-5))" => Ok(Some("(* 2 (- 3 (- 4 5)))".to_string())) ; "nested_parentheses")]
+#[test_case("1+" => Err(ExpectedMoreInput) ; "missing_operand")]
+#[test_case("-" => Err(ExpectedMoreInput) ; "missing_prefix_operand")]
+#[test_case("*1" => Err(UnexpectedInput) ; "missing_lhs")]
+#[test_case("" => Err(ExpectedMoreInput) ; "empty")]
+fn finalize(input: &str) -> ParseResult<Option<String>, NumberError> {
+    arithmetic().finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn split_feeds(bufsize: usize) {
+    let output = arithmetic()
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(b"12*(345+6)^2^10-78!".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(output, "(- (* 12 (^ (+ 345 6) (^ 2 10))) (! 78))");
+}
+
+#[test]
+fn ends_before_other_input() {
+    let Update { consumed, outcome } = arithmetic().feed("1+2*3;4").unwrap();
+
+    assert_eq!(consumed, 5);
+    assert!(matches!(outcome, Parsed(s) if s == "(+ 1 (* 2 3))"));
+}
+
+#[test]
+fn incomplete_operator() {
+    let expr = Expression::new(uint::<u64, _>())
+        .infix(literal("**"), 2, Right, |l: u64, _, r| l.pow(r as u32))
+        .infix(literal("*"), 1, Left, |l, _, r| l * r);
+
+    let Update { consumed, outcome } = expr.feed("2*").unwrap();
+    assert_eq!(consumed, 1);
+    let Next(expr) = outcome else {
+        panic!("`*` might begin `**`");
+    };
+    assert_eq!(expr.finalize("*3**2"), Ok(Some(18)));
+}
+
+#[test]
+fn atom_error() {
+    let result = Expression::new(uint::<u8, _>())
+        .infix(literal("+"), 1, Left, |l, _, r| l + r)
+        .finalize("1+300");
+
+    assert_eq!(result, Err(ParseError::Custom(NumberError::Overflow)));
+}
+
+#[test]
+fn whitespace() {
+    let sum = Expression::new(lexeme(uint::<u32, _>()))
+        .infix(lexeme(literal("+")), 1, Left, |l, _, r| l + r)
+        .infix(lexeme(literal("*")), 2, Left, |l, _, r| l * r)
+        .finalize("1 + 2 *\t3 ");
+
+    assert_eq!(sum, Ok(Some(7)));
+}
+
+#[test]
+fn clone_started() {
+    let Update { consumed, outcome } = arithmetic().feed("1+2*").unwrap();
+    assert_eq!(consumed, 4);
+    let Next(started) = outcome else {
+        panic!("an operand must follow");
+    };
+
+    assert_eq!(
+        started.clone().finalize("3"),
+        Ok(Some("(+ 1 (* 2 3))".to_string()))
+    );
+    assert_eq!(
+        started.finalize("4!"),
+        Ok(Some("(+ 1 (* 2 (! 4)))".to_string()))
+    );
+}
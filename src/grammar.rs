@@ -0,0 +1,160 @@
+//! Grammars defined at runtime, such as from configuration data, via [Grammar]
+//!
+//! A [Grammar] is compiled with [Grammar::compile] into a [BoxedParser] over bytes which yields a [Value] tree. This supports new record formats without recompiling. Compiling rejects a grammar which would repeat without consuming input, so untrusted grammars cannot make parsing loop forever.
+use std::convert::Infallible;
+
+use either::Either;
+
+use crate::combinator::{choice, lazy};
+use crate::parser::{BoxedParser, PushParser};
+use crate::primitive::{empty, literal, pure, take};
+use crate::sequence::SequenceParser;
+
+/// A runtime grammar over bytes
+#[derive(Clone, Debug, PartialEq)]
+pub enum Grammar {
+    /// Exactly these bytes, yielding [Value::Bytes]
+    Literal(Vec<u8>),
+    /// Any bytes of exactly this width, yielding [Value::Bytes]
+    Field(usize),
+    /// Each grammar in order, yielding [Value::List]
+    Sequence(Vec<Grammar>),
+    /// The first alternative which matches, yielding [Value::Choice]
+    Choice(Vec<Grammar>),
+    /// Zero or more repetitions, yielding [Value::List]
+    ///
+    /// The repeated grammar must not match empty input, or else compiling fails with [GrammarError::EmptyRepeat].
+    Repeat(Box<Grammar>),
+    /// Zero or more items separated by a delimiter, yielding a [Value::List] of the items
+    ///
+    /// If the delimiter is empty, the item must not match empty input, or else compiling fails with [GrammarError::EmptyRepeat].
+    Delimited {
+        /// The grammar of each item
+        item: Box<Grammar>,
+        /// The delimiter between items
+        delimiter: Vec<u8>,
+    },
+}
+
+/// The generic value tree produced by parsing a [Grammar]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// The input matched by [Grammar::Literal] or [Grammar::Field]
+    Bytes(Vec<u8>),
+    /// The values of [Grammar::Sequence], [Grammar::Repeat], or [Grammar::Delimited]
+    List(Vec<Value>),
+    /// The index and value of the matching [Grammar::Choice] alternative
+    Choice(usize, Box<Value>),
+}
+
+/// An error from [Grammar::compile] for a grammar which cannot be parsed
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum GrammarError {
+    /// A [Grammar::Repeat] or [Grammar::Delimited] repeats a grammar which can match empty input, so parsing would never end
+    #[error("repeated grammar can match empty input")]
+    EmptyRepeat,
+}
+
+impl Grammar {
+    /// Compile this grammar into a parser, or fail if it repeats a grammar which can match empty input
+    pub fn compile(&self) -> Result<BoxedParser<'_, [u8], Value, Infallible>, GrammarError> {
+        self.check()?;
+        Ok(self.build())
+    }
+
+    /// Reject repetitions which would not consume input
+    fn check(&self) -> Result<(), GrammarError> {
+        match self {
+            Grammar::Literal(_) | Grammar::Field(_) => Ok(()),
+            Grammar::Sequence(items) | Grammar::Choice(items) => {
+                items.iter().try_for_each(Grammar::check)
+            }
+            Grammar::Repeat(item) => {
+                item.check()?;
+                if item.matches_empty() {
+                    Err(GrammarError::EmptyRepeat)
+                } else {
+                    Ok(())
+                }
+            }
+            Grammar::Delimited { item, delimiter } => {
+                item.check()?;
+                if delimiter.is_empty() && item.matches_empty() {
+                    Err(GrammarError::EmptyRepeat)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Whether this grammar can match without consuming input
+    fn matches_empty(&self) -> bool {
+        match self {
+            Grammar::Literal(bytes) => bytes.is_empty(),
+            Grammar::Field(width) => *width == 0,
+            Grammar::Sequence(items) => items.iter().all(Grammar::matches_empty),
+            Grammar::Choice(alternatives) => alternatives.iter().any(Grammar::matches_empty),
+            Grammar::Repeat(_) | Grammar::Delimited { .. } => true,
+        }
+    }
+
+    /// Build the parser of a checked grammar
+    fn build(&self) -> BoxedParser<'_, [u8], Value, Infallible> {
+        match self {
+            Grammar::Literal(bytes) => literal(bytes.as_slice())
+                .map_output(|b| Value::Bytes(b.to_vec()))
+                .boxed(),
+            Grammar::Field(width) => take(*width).map_output(Value::Bytes).boxed(),
+            Grammar::Sequence(items) => items
+                .iter()
+                .fold(pure(vec![]).boxed(), |acc, item| {
+                    acc.then(item.build())
+                        .map_output(|(mut values, value)| {
+                            values.push(value);
+                            values
+                        })
+                        .map_error(Either::into_inner)
+                        .boxed()
+                })
+                .map_output(Value::List)
+                .boxed(),
+            Grammar::Choice(alternatives) => choice(
+                alternatives.iter().enumerate().map(|(i, alt)| {
+                    alt.build()
+                        .map_output(move |value| Value::Choice(i, Box::new(value)))
+                        .boxed()
+                }),
+                empty(),
+            ),
+            Grammar::Repeat(item) => lazy(|| item.build())
+                .repeated()
+                .collect()
+                .map_output(Value::List)
+                .boxed(),
+            Grammar::Delimited { item, delimiter } => item
+                .build()
+                .then(
+                    lazy(|| {
+                        literal(delimiter.as_slice())
+                            .then(item.build())
+                            .map_output(|(_, value)| value)
+                            .map_error(Either::into_inner)
+                    })
+                    .repeated()
+                    .collect(),
+                )
+                .map_output(|(first, mut rest): (Value, Vec<Value>)| {
+                    rest.insert(0, first);
+                    rest
+                })
+                .map_error(Either::into_inner)
+                .optional()
+                .map_output(|optitems| Value::List(optitems.unwrap_or_default()))
+                .boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
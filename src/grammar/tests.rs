@@ -0,0 +1,100 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::grammar::Grammar::{self, Choice, Delimited, Field, Literal, Repeat, Sequence};
+use crate::grammar::GrammarError::{self, EmptyRepeat};
+use crate::grammar::Value::{self, Bytes, List};
+use crate::parser::ByteParser;
+
+fn lit(s: &str) -> Grammar {
+    Literal(s.as_bytes().to_vec())
+}
+
+fn bytes(s: &str) -> Value {
+    Bytes(s.as_bytes().to_vec())
+}
+
+fn record() -> Grammar {
+    Repeat(Box::new(Sequence(vec![lit("ID:"), Field(4), lit(";")])))
+}
+
+fn flags() -> Grammar {
+    Delimited {
+        item: Box::new(Choice(vec![lit("on"), lit("off")])),
+        delimiter: b",".to_vec(),
+    }
+}
+
+fn choice(i: usize, s: &str) -> Value {
+    Value::Choice(i, Box::new(bytes(s)))
+}
+
+#[test_case(
+    record(),
+    "ID:1234;ID:abcd;"
+    => Ok(List(vec![
+        List(vec![bytes("ID:"), bytes("1234"), bytes(";")]),
+        List(vec![bytes("ID:"), bytes("abcd"), bytes(";")]),
+    ]))
+    ; "record_repeat"
+)]
+#[test_case(record(), "" => Ok(List(vec![])) ; "record_repeat_empty")]
+#[test_case(
+    flags(),
+    "on,off,on"
+    => Ok(List(vec![choice(0, "on"), choice(1, "off"), choice(0, "on")]))
+    ; "flags_delimited"
+)]
+#[test_case(flags(), "" => Ok(List(vec![])) ; "flags_delimited_empty")]
+#[test_case(
+    Sequence(vec![Field(2), lit("=")]),
+    "ab="
+    => Ok(List(vec![bytes("ab"), bytes("=")]))
+    ; "field_then_literal"
+)]
+#[test_case(Sequence(vec![]), "" => Ok(List(vec![])) ; "empty_sequence")]
+#[test_case(Choice(vec![]), "x" => Err(UnexpectedInput) ; "empty_choice")]
+#[test_case(Sequence(vec![Field(4)]), "abc" => Err(ExpectedMoreInput) ; "short_field")]
+#[test_case(
+    Sequence(vec![lit("a"), Choice(vec![lit("b"), lit("c")])]),
+    "ax"
+    => Err(UnexpectedInput)
+    ; "no_alternative"
+)]
+fn parse(grammar: Grammar, input: &str) -> ParseResult<Value, ()> {
+    let parse = |bufsize| {
+        grammar
+            .compile()
+            .unwrap()
+            .parse_reader_with_bufsize::<_, Infallible>(input.as_bytes(), bufsize)
+            .map_err(|e| e.map_custom(|_| ()))
+    };
+
+    let result = parse(1 << 14);
+    for bufsize in [1, 2, 5] {
+        assert_eq!(parse(bufsize), result, "bufsize {bufsize}");
+    }
+    result
+}
+
+#[test_case(record() => Ok(()) ; "record")]
+#[test_case(Repeat(Box::new(Sequence(vec![]))) => Err(EmptyRepeat) ; "repeat_empty_sequence")]
+#[test_case(Repeat(Box::new(Choice(vec![lit("a"), lit("")]))) => Err(EmptyRepeat) ; "repeat_empty_alternative")]
+#[test_case(Repeat(Box::new(Repeat(Box::new(lit("a"))))) => Err(EmptyRepeat) ; "repeat_repeat")]
+#[test_case(Sequence(vec![lit("a"), Repeat(Box::new(Field(0)))]) => Err(EmptyRepeat) ; "nested")]
+#[test_case(
+    Delimited { item: Box::new(Field(0)), delimiter: vec![] }
+    => Err(EmptyRepeat)
+    ; "delimited_empty"
+)]
+#[test_case(
+    Delimited { item: Box::new(Field(0)), delimiter: b",".to_vec() }
+    => Ok(())
+    ; "delimited_empty_item"
+)]
+fn compile(grammar: Grammar) -> Result<(), GrammarError> {
+    grammar.compile().map(drop)
+}
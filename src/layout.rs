@@ -0,0 +1,60 @@
+//! Indentation-sensitive layout for Python-like and YAML-like text, built on [IndentStack] user state
+//!
+//! Layout parsers measure the column at the start of each line, after skipping blank lines, which contain only spaces, tabs, and line endings. A tab advances the column to the next multiple of [TAB_WIDTH]. When leading whitespace ends at the end of a buffer, it is retained until the next feed decides the column, so a line's indentation may span any number of feeds.
+//!
+//! - [indentation] yields the column of the next nonblank line, without user state.
+//! - [layout] yields a [LayoutEvent] for each line, as a tokenizer would, maintaining the [IndentStack].
+//! - [indented_block] and [aligned_block] parse a sequence of lines at one indentation level, where the line parser may itself contain nested blocks.
+//!
+//! [layout], [indented_block], and [aligned_block] require an [IndentStack] as user state, such as with [ByteParser::parse_reader_with_state]. A [Block] which fails closes any level it opened, so it may be [optional](crate::parser::PushParser::optional), but changes made by parsers which succeed are not undone when an enclosing [Or](crate::combinator::Or) backtracks.
+mod block;
+mod event;
+mod indentation;
+mod stack;
+
+pub use self::block::{aligned_block, indented_block, Block};
+pub use self::event::{layout, Layout, LayoutEvent};
+pub use self::indentation::{indentation, Indentation};
+pub use self::stack::IndentStack;
+
+#[cfg(doc)]
+use crate::parser::ByteParser;
+
+/// The column width of a tab stop
+pub const TAB_WIDTH: usize = 8;
+
+/// A custom error from layout parsers
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum LayoutError {
+    /// A line is indented deeper than its block
+    #[error("unexpected indentation at column {0}")]
+    UnexpectedIndent(usize),
+    /// A line is dedented to a column which does not match any enclosing block
+    #[error("dedent to column {0} does not match any enclosing indentation")]
+    InconsistentDedent(usize),
+    /// The first line of an indented block is not deeper than the enclosing block
+    #[error("expected indentation deeper than column {0}")]
+    ExpectedIndent(usize),
+}
+
+/// Scan the blank lines and indentation at the start of `bytes`
+///
+/// Yields the length of the blank lines, and if the indentation of the following line is complete, its length and column.
+fn scan_line_start(bytes: &[u8]) -> (usize, Option<(usize, usize)>) {
+    let mut blank = 0;
+    let mut column = 0;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match b {
+            b' ' => column += 1,
+            b'\t' => column += TAB_WIDTH - column % TAB_WIDTH,
+            b'\r' | b'\n' => {
+                blank = i + 1;
+                column = 0;
+            }
+            _ => return (blank, Some((i - blank, column))),
+        }
+    }
+
+    (blank, None)
+}
@@ -0,0 +1,271 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use either::Either::{self, Left, Right};
+
+use crate::buffer::BufRef;
+use crate::combinator::require_state;
+use crate::error::ParseError::{Custom, ExpectedMoreInput};
+use crate::error::ParseResult;
+use crate::layout::{scan_line_start, IndentStack, LayoutError};
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [Block] of one or more lines of `line`, indented deeper than the enclosing level
+pub fn indented_block<B, P>(line: P) -> Block<P, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+    P: ParserCore<B> + Clone,
+{
+    Block::new(line, true)
+}
+
+/// Construct a [Block] of zero or more lines of `line`, aligned with the enclosing level, such as the top level of a file
+pub fn aligned_block<B, P>(line: P) -> Block<P, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+    P: ParserCore<B> + Clone,
+{
+    Block::new(line, false)
+}
+
+/// A [Block] parses lines at one indentation level with clones of `P`, yielding their outputs
+///
+/// Input must begin at the start of a line. Before each line, blank lines and leading whitespace are skipped, and `P` then parses the rest of the line, including its line ending, along with any nested blocks. The block ends before the first line indented less than its level, leaving that line's leading whitespace unconsumed for the enclosing block, or at the end of input. A line indented deeper than the level is an error, as nested blocks must be parsed by `P`.
+///
+/// An [indented_block] opens its level on the [IndentStack] at the column of its first line, and closes it when the block ends.
+///
+/// Parsing fails with [ParseError::MissingState](crate::error::ParseError::MissingState) if the user state is not an [IndentStack].
+pub struct Block<P, B>
+where
+    B: ?Sized,
+    P: ParserCore<B>,
+{
+    template: P,
+    current: Option<P>,
+    items: Vec<P::Output>,
+    level: Option<usize>,
+    indented: bool,
+    phantom: PhantomData<B>,
+}
+
+impl<P, B> Block<P, B>
+where
+    B: ?Sized,
+    P: ParserCore<B> + Clone,
+{
+    fn new(template: P, indented: bool) -> Self {
+        Block {
+            template,
+            current: None,
+            items: vec![],
+            level: None,
+            indented,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Decide whether a line at `column` continues this block, or else close the block
+    fn continues(
+        &mut self,
+        ctx: &mut Context<'_>,
+        column: usize,
+    ) -> ParseResult<bool, Either<LayoutError, P::Error>> {
+        let stack: &mut IndentStack = require_state(ctx)?;
+
+        let level = match self.level {
+            Some(level) => level,
+            None if self.indented => {
+                let enclosing = stack.level();
+                if column <= enclosing {
+                    return Err(Custom(Left(LayoutError::ExpectedIndent(enclosing))));
+                }
+                stack.push(column);
+                self.level = Some(column);
+                column
+            }
+            None => *self.level.insert(stack.level()),
+        };
+
+        if column == level {
+            Ok(true)
+        } else if column < level {
+            if self.indented {
+                stack.pop();
+            }
+            Ok(false)
+        } else {
+            self.abandon(ctx);
+            Err(Custom(Left(LayoutError::UnexpectedIndent(column))))
+        }
+    }
+
+    /// Close the level this block opened, upon an error, so that an enclosing alternative sees the stack as before
+    fn abandon(&self, ctx: &mut Context<'_>) {
+        if self.indented && self.level.is_some() {
+            if let Some(stack) = ctx.state::<IndentStack>() {
+                stack.pop();
+            }
+        }
+    }
+}
+
+impl<P, B> Block<P, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+    P: ParserCore<B> + Clone,
+{
+    /// Complete the current line and close this block at the end of input
+    fn finish(
+        mut self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<<Self as ParserCore<B>>::Output>, <Self as ParserCore<B>>::Error> {
+        if let Some(current) = self.current.take() {
+            let output = match current.finalize_with(ctx.reborrow(), buffer) {
+                Ok(Some(output)) => output,
+                Ok(None) => {
+                    self.abandon(&mut ctx);
+                    return Err(ExpectedMoreInput);
+                }
+                Err(e) => {
+                    self.abandon(&mut ctx);
+                    return Err(e.map_custom(Right));
+                }
+            };
+            self.items.push(output);
+        }
+
+        if self.indented {
+            if self.level.is_none() {
+                return Err(ExpectedMoreInput);
+            }
+            require_state::<IndentStack, _>(&mut ctx)?.pop();
+        }
+
+        Ok(Some(self.items))
+    }
+}
+
+impl<P, B> Clone for Block<P, B>
+where
+    B: ?Sized,
+    P: ParserCore<B> + Clone,
+    P::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Block {
+            template: self.template.clone(),
+            current: self.current.clone(),
+            items: self.items.clone(),
+            level: self.level,
+            indented: self.indented,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, B> fmt::Debug for Block<P, B>
+where
+    B: ?Sized,
+    P: ParserCore<B> + fmt::Debug,
+    P::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Block")
+            .field("template", &self.template)
+            .field("current", &self.current)
+            .field("items", &self.items)
+            .field("level", &self.level)
+            .field("indented", &self.indented)
+            .finish()
+    }
+}
+
+impl<P, B> ParserCore<B> for Block<P, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+    P: ParserCore<B> + Clone,
+{
+    type Output = Vec<P::Output>;
+    type Error = Either<LayoutError, P::Error>;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        mut self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let mut consumed = 0;
+        loop {
+            let rest = buffer.drop_up_to(consumed);
+
+            if let Some(current) = self.current.take() {
+                let update = match current.feed_with(ctx.advance(consumed), rest) {
+                    Ok(update) => update,
+                    Err(e) => {
+                        self.abandon(&mut ctx);
+                        return Err(e.map_custom(Right));
+                    }
+                };
+                consumed += update.consumed;
+                match update.outcome {
+                    Next(current) => {
+                        self.current = Some(current);
+                        return Ok(Update {
+                            consumed,
+                            outcome: Next(self),
+                        });
+                    }
+                    Parsed(output) => self.items.push(output),
+                }
+                continue;
+            }
+
+            match scan_line_start(rest.as_ref()) {
+                (blank, Some((indent, column))) => {
+                    if !self.continues(&mut ctx, column)? {
+                        return Ok(Update {
+                            consumed: consumed + blank,
+                            outcome: Parsed(self.items),
+                        });
+                    }
+                    consumed += blank + indent;
+                    self.current = Some(self.template.clone());
+                }
+                // Retain partial indentation until its column is decided:
+                (blank, None) => {
+                    return Ok(Update {
+                        consumed: consumed + blank,
+                        outcome: Next(self),
+                    });
+                }
+            }
+        }
+    }
+
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let Update { consumed, outcome } = self.feed_with(ctx.reborrow(), buffer)?;
+        match outcome {
+            Parsed(items) => Ok(Some(items)),
+            Next(block) => block.finish(ctx.advance(consumed), buffer.drop_up_to(consumed)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
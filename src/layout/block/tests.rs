@@ -0,0 +1,103 @@
+use std::convert::Infallible;
+
+use either::Either::{self, Left};
+use test_case::test_case;
+
+use crate::combinator::{recursive, Recursive};
+use crate::error::ParseError::Custom;
+use crate::error::ParseResult;
+use crate::layout::{aligned_block, indented_block, IndentStack, LayoutError};
+use crate::parser::{ByteParser, PushParser};
+use crate::primitive::text::line;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node(String, Vec<Node>);
+
+fn leaf(name: &str) -> Node {
+    Node(name.to_string(), vec![])
+}
+
+/// A line, followed by an optional indented block of child nodes
+fn node<'a>() -> Recursive<'a, [u8], Node, Infallible> {
+    recursive(|node| {
+        line()
+            .then(indented_block(node).optional())
+            .map_output(|(name, children)| {
+                Node(
+                    String::from_utf8(name).unwrap(),
+                    children.unwrap_or_default(),
+                )
+            })
+            .map_error(Either::into_inner)
+    })
+}
+
+fn parse_tree(
+    src: &str,
+    bufsize: usize,
+) -> ParseResult<Vec<Node>, Either<Either<LayoutError, Infallible>, std::io::Error>> {
+    let mut stack = IndentStack::new();
+    let nodes = aligned_block(node()).parse_reader_with_bufsize_and_state(
+        src.as_bytes(),
+        bufsize,
+        &mut stack,
+    );
+    assert_eq!(stack.depth(), 0);
+    nodes
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn tree(bufsize: usize) {
+    let src = "root\n  a\n    a1\n\n    a2\n  b\ntop2\n";
+
+    assert_eq!(
+        parse_tree(src, bufsize).unwrap(),
+        vec![
+            Node(
+                "root".to_string(),
+                vec![
+                    Node("a".to_string(), vec![leaf("a1"), leaf("a2")]),
+                    leaf("b")
+                ]
+            ),
+            leaf("top2"),
+        ]
+    );
+}
+
+#[test_case("" => Vec::<Node>::new() ; "empty")]
+#[test_case("\n  \n" => Vec::<Node>::new() ; "blank")]
+#[test_case("x\n  y" => vec![Node("x".to_string(), vec![leaf("y")])] ; "no_final_line_ending")]
+#[test_case("x\r\n\ty\r\n\t\tz\r\n" => vec![Node("x".to_string(), vec![Node("y".to_string(), vec![leaf("z")])])] ; "tabs_and_crlf")]
+fn trees(src: &str) -> Vec<Node> {
+    parse_tree(src, 2).unwrap()
+}
+
+#[test_case("  a\n" => Some(LayoutError::UnexpectedIndent(2)) ; "indented_top")]
+#[test_case("a\n  b\n c\n" => Some(LayoutError::UnexpectedIndent(1)) ; "inconsistent_dedent")]
+// The block of "a" fails at "   d", so it is optional and the top level fails at "  b":
+#[test_case("a\n  b\n    c\n   d\n" => Some(LayoutError::UnexpectedIndent(2)) ; "inconsistent_nested_dedent")]
+fn errors(src: &str) -> Option<LayoutError> {
+    match parse_tree(src, 1) {
+        Err(Custom(Left(Left(e)))) => Some(e),
+        _ => None,
+    }
+}
+
+#[test_case("a:\n  b\n" => Ok(vec!["b".to_string()]) ; "indented")]
+#[test_case("a:\nb\n" => Err(Custom(Left(LayoutError::ExpectedIndent(0)))) ; "not_indented")]
+fn required_block(src: &str) -> ParseResult<Vec<String>, Either<LayoutError, Infallible>> {
+    let mut stack = IndentStack::new();
+    line()
+        .then(indented_block(
+            line().map_output(|l| String::from_utf8(l).unwrap()),
+        ))
+        .map_output(|(_, lines)| lines)
+        .map_error(Either::unwrap_right)
+        .boxed()
+        .parse_reader_with_state(src.as_bytes(), &mut stack)
+        .map_err(|e| e.map_custom(Either::unwrap_left))
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::combinator::require_state;
+use crate::error::ParseError::Custom;
+use crate::error::ParseResult;
+use crate::layout::{scan_line_start, IndentStack, LayoutError};
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [Layout] parser which yields the [LayoutEvent] at the start of the next nonblank line
+pub fn layout<B>() -> Layout<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    Layout(PhantomData)
+}
+
+/// The change in indentation at the start of a line, relative to the [IndentStack]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LayoutEvent {
+    /// The line is deeper than the innermost level, which it opens
+    Indent,
+    /// The line closes this many levels, and is aligned with the level enclosing them
+    Dedent(usize),
+    /// The line is aligned with the innermost level
+    Aligned,
+}
+
+/// A [Layout] parser skips blank lines and the leading whitespace of the next line, yielding its [LayoutEvent] and updating the [IndentStack] user state
+///
+/// Input must begin at the start of a line. The end of input behaves as a line at column 0, which closes every open level.
+///
+/// Parsing fails with [ParseError::MissingState](crate::error::ParseError::MissingState) if the user state is not an [IndentStack].
+pub struct Layout<B>(PhantomData<B>)
+where
+    B: ?Sized;
+
+impl<B> Layout<B>
+where
+    B: ?Sized,
+{
+    fn event(ctx: &mut Context<'_>, column: usize) -> ParseResult<LayoutEvent, LayoutError> {
+        let stack: &mut IndentStack = require_state(ctx)?;
+
+        if column > stack.level() {
+            stack.push(column);
+            return Ok(LayoutEvent::Indent);
+        }
+
+        let mut closed = 0;
+        while column < stack.level() {
+            stack.pop();
+            closed += 1;
+        }
+
+        if column != stack.level() {
+            Err(Custom(LayoutError::InconsistentDedent(column)))
+        } else if closed > 0 {
+            Ok(LayoutEvent::Dedent(closed))
+        } else {
+            Ok(LayoutEvent::Aligned)
+        }
+    }
+}
+
+impl<B> Clone for Layout<B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Layout(PhantomData)
+    }
+}
+
+impl<B> fmt::Debug for Layout<B>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layout").finish()
+    }
+}
+
+impl<B> ParserCore<B> for Layout<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    type Output = LayoutEvent;
+    type Error = LayoutError;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match scan_line_start(buffer.as_ref()) {
+            (blank, Some((indent, column))) => Ok(Update {
+                consumed: blank + indent,
+                outcome: Parsed(Self::event(&mut ctx, column)?),
+            }),
+            // Retain partial indentation until its column is decided:
+            (blank, None) => Ok(Update {
+                consumed: blank,
+                outcome: Next(self),
+            }),
+        }
+    }
+
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let column = match scan_line_start(buffer.as_ref()) {
+            (_, Some((_, column))) => column,
+            (_, None) => 0,
+        };
+
+        Self::event(&mut ctx, column).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests;
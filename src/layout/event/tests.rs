@@ -0,0 +1,72 @@
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, MissingState};
+use crate::error::ParseResult;
+use crate::layout::LayoutEvent::{Aligned, Dedent, Indent};
+use crate::layout::{layout, IndentStack, LayoutError, LayoutEvent};
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{Context, ParserCore, Update};
+use crate::primitive::text::line;
+
+/// The event of each line of `src`, which must end with a line ending, followed by the event at the end of input
+fn events(src: &str) -> ParseResult<Vec<LayoutEvent>, LayoutError> {
+    let mut stack = IndentStack::new();
+    let mut events = vec![];
+    let mut rest = src;
+
+    while !rest.trim().is_empty() {
+        let Update { consumed, outcome } =
+            layout().feed_with(Context::with_state(0, &mut stack), rest)?;
+        let Parsed(event) = outcome else {
+            unreachable!("lines are complete");
+        };
+        events.push(event);
+        rest = &rest[consumed..];
+
+        let consumed = ParserCore::<str>::feed(line(), rest).unwrap().consumed;
+        rest = &rest[consumed..];
+    }
+
+    let end = layout().finalize_with(Context::with_state(0, &mut stack), rest)?;
+    events.extend(end);
+    assert_eq!(stack.depth(), 0);
+    Ok(events)
+}
+
+#[test_case("a\nb\n" => Ok(vec![Aligned, Aligned, Aligned]) ; "flat")]
+#[test_case("a\n  b\n  c\n    d\ne\n" => Ok(vec![Aligned, Indent, Aligned, Indent, Dedent(2), Aligned]) ; "nested")]
+#[test_case("a\n  b\n    c\n" => Ok(vec![Aligned, Indent, Indent, Dedent(2)]) ; "end_of_input_closes")]
+#[test_case("a\n\n   \n  b\n\n" => Ok(vec![Aligned, Indent, Dedent(1)]) ; "blank_lines")]
+#[test_case("a\r\n  b\r\n" => Ok(vec![Aligned, Indent, Dedent(1)]) ; "crlf")]
+#[test_case("a\n\tb\n        c\n" => Ok(vec![Aligned, Indent, Aligned, Dedent(1)]) ; "tab_stop")]
+#[test_case("a\n    b\n  c\n" => Err(Custom(LayoutError::InconsistentDedent(2))) ; "inconsistent_dedent")]
+fn python_like(src: &str) -> ParseResult<Vec<LayoutEvent>, LayoutError> {
+    events(src)
+}
+
+#[test]
+fn indentation_split_across_feeds() {
+    let mut stack = IndentStack::new();
+
+    let Update { consumed, outcome } = layout()
+        .feed_with(Context::with_state(0, &mut stack), "\n  ")
+        .unwrap();
+    assert_eq!(consumed, 1);
+    let Next(parser) = outcome else {
+        panic!("indentation is incomplete");
+    };
+
+    let Update { consumed, outcome } = parser
+        .feed_with(Context::with_state(1, &mut stack), "    x")
+        .unwrap();
+    assert_eq!(consumed, 4);
+    assert!(matches!(outcome, Parsed(Indent)));
+    assert_eq!(stack.level(), 4);
+}
+
+#[test]
+fn requires_stack() {
+    let result = ParserCore::<str>::feed(layout(), "x");
+
+    assert!(matches!(result, Err(MissingState(name)) if name.ends_with("IndentStack")));
+}
@@ -0,0 +1,75 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::error::ParseResult;
+use crate::layout::scan_line_start;
+use crate::parser::{ParserCore, Update};
+
+/// Construct an [Indentation] parser which yields the column of the next nonblank line
+pub fn indentation<B>() -> Indentation<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    Indentation(PhantomData)
+}
+
+/// An [Indentation] parser skips blank lines and the leading whitespace of the next line, yielding its column
+///
+/// Input must begin at the start of a line. At the end of input, the column is 0, so that a layout closes every open block.
+pub struct Indentation<B>(PhantomData<B>)
+where
+    B: ?Sized;
+
+impl<B> Clone for Indentation<B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Indentation(PhantomData)
+    }
+}
+
+impl<B> fmt::Debug for Indentation<B>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Indentation").finish()
+    }
+}
+
+impl<B> ParserCore<B> for Indentation<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    type Output = usize;
+    type Error = Infallible;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match scan_line_start(buffer.as_ref()) {
+            (blank, Some((indent, column))) => Ok(Update {
+                consumed: blank + indent,
+                outcome: Parsed(column),
+            }),
+            // Retain partial indentation until its column is decided:
+            (blank, None) => Ok(Update {
+                consumed: blank,
+                outcome: Next(self),
+            }),
+        }
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        match scan_line_start(buffer.as_ref()) {
+            (_, Some((_, column))) => Ok(Some(column)),
+            (_, None) => Ok(Some(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
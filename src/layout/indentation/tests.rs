@@ -0,0 +1,41 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseResult;
+use crate::layout::indentation;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, Update};
+use crate::primitive::take;
+
+#[test_case("x" => Ok(Update { consumed: 0, outcome: Parsed(0) }) ; "unindented")]
+#[test_case("    x" => Ok(Update { consumed: 4, outcome: Parsed(4) }) ; "spaces")]
+#[test_case("\t x" => Ok(Update { consumed: 2, outcome: Parsed(9) }) ; "tab_then_space")]
+#[test_case("  \t x" => Ok(Update { consumed: 4, outcome: Parsed(9) }) ; "space_then_tab")]
+#[test_case("  \n\r\n   x" => Ok(Update { consumed: 8, outcome: Parsed(3) }) ; "blank_lines")]
+#[test_case("  " => Ok(Update { consumed: 0, outcome: Next(()) }) ; "partial")]
+#[test_case("  \n  " => Ok(Update { consumed: 3, outcome: Next(()) }) ; "blank_then_partial")]
+fn feed(input: &str) -> ParseResult<Update<(), usize>, Infallible> {
+    indentation().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("  x" => Ok(Some(2)) ; "line")]
+#[test_case("  \n  " => Ok(Some(0)) ; "end_of_input")]
+#[test_case("" => Ok(Some(0)) ; "empty")]
+fn finalize(input: &str) -> ParseResult<Option<usize>, Infallible> {
+    indentation().finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn split_feeds(bufsize: usize) {
+    let (column, rest) = indentation()
+        .then(take(1))
+        .parse_reader_with_bufsize::<_, Infallible>(b"\n \n\t    x".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(column, 12);
+    assert_eq!(rest, b"x");
+}
@@ -0,0 +1,33 @@
+/// The stack of open indentation levels, which layout parsers require as user state
+///
+/// The outermost level is column 0, which is always open and so is not stored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IndentStack {
+    levels: Vec<usize>,
+}
+
+impl IndentStack {
+    /// Construct a stack with only the outermost level open
+    pub fn new() -> Self {
+        IndentStack::default()
+    }
+
+    /// The column of the innermost open level
+    pub fn level(&self) -> usize {
+        self.levels.last().copied().unwrap_or(0)
+    }
+
+    /// The number of levels open within the outermost level
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub(super) fn push(&mut self, column: usize) {
+        debug_assert!(column > self.level());
+        self.levels.push(column);
+    }
+
+    pub(super) fn pop(&mut self) {
+        self.levels.pop();
+    }
+}
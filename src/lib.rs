@@ -12,7 +12,7 @@
 //!
 //! ## Incremental parsing
 //!
-//! Some applications need direct access to incremental parsing, such as in a user interface which is attempting to parse user input as it is written, or those with data sources which don't fit neatly into the [ByteParser] methods. Also, some applications may use different kinds of input such as sequence of application specific tokens and parsers over those. All of these cases need to use [ParserCore] directly:
+//! Some applications need direct access to incremental parsing, such as in a user interface which is attempting to parse user input as it is written, or those with data sources which don't fit neatly into the [ByteParser] methods. Also, some applications may use different kinds of input such as sequence of application specific tokens and parsers over those, which [Pipe](sequence::Pipe) connects to a lexer. All of these cases need to use [ParserCore] directly:
 //!
 //! The fundamental incremental parsing functionality comes from two methods, the first of which is [ParserCore::feed]. [ParserCore::feed] produces a [Result] which either signifies a parse error or an [Update]. Updates indicate how many input elements were consumed and whether a [ParserCore::Output] was parsed or the parser has a newly updated state.
 //!
@@ -34,6 +34,9 @@
 pub mod buffer;
 pub mod combinator;
 pub mod error;
+pub mod expression;
+pub mod grammar;
+pub mod layout;
 pub mod parser;
 pub mod primitive;
 pub mod sequence;
@@ -1,16 +1,26 @@
 //! [PushParser] and other traits & types for parser implementors and consumers
+mod boxed;
 mod byteparser;
+mod context;
 mod core;
+mod decodewith;
+mod downstream;
 mod intoutf8;
 mod outcome;
 mod push;
 mod text;
+mod transducer;
 mod update;
 
+pub use self::boxed::{BoxedParser, DynParserCore};
 pub use self::byteparser::ByteParser;
+pub use self::context::Context;
 pub use self::core::ParserCore;
+pub use self::decodewith::DecodeWith;
+pub(crate) use self::downstream::Downstream;
 pub use self::intoutf8::IntoUtf8Parser;
 pub use self::outcome::Outcome;
 pub use self::push::PushParser;
 pub use self::text::TextParser;
+pub use self::transducer::Transducer;
 pub use self::update::Update;
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::buffer::BufRef;
+use crate::error::{ParseResult, ParseResultUpdateExt};
+use crate::parser::{Context, ParserCore, Update};
+
+#[cfg(doc)]
+use crate::parser::PushParser;
+
+/// A dyn-compatible form of [ParserCore], implemented for every [Clone] [ParserCore]
+///
+/// Consumers typically use [BoxedParser] (via [PushParser::boxed]) rather than this trait directly.
+pub trait DynParserCore<'a, B, O, E>
+where
+    B: ?Sized,
+{
+    /// The boxed equivalent of [ParserCore::feed_with]
+    fn feed_boxed(
+        self: Box<Self>,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<BoxedParser<'a, B, O, E>, O>, E>;
+
+    /// The boxed equivalent of [ParserCore::finalize_with]
+    fn finalize_boxed(self: Box<Self>, ctx: Context<'_>, buffer: &B) -> ParseResult<Option<O>, E>;
+
+    /// The boxed equivalent of [ParserCore::finalize_consumed]
+    fn finalize_consumed_boxed(
+        self: Box<Self>,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, O)>, E>
+    where
+        B: BufRef;
+
+    /// The boxed equivalent of [Clone::clone]
+    fn clone_boxed(&self) -> BoxedParser<'a, B, O, E>;
+}
+
+impl<'a, B, P> DynParserCore<'a, B, P::Output, P::Error> for P
+where
+    B: ?Sized,
+    P: ParserCore<B> + Clone + 'a,
+{
+    fn clone_boxed(&self) -> BoxedParser<'a, B, P::Output, P::Error> {
+        BoxedParser::new(self.clone())
+    }
+
+    fn feed_boxed(
+        self: Box<Self>,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<BoxedParser<'a, B, P::Output, P::Error>, P::Output>, P::Error> {
+        (*self).feed_with(ctx, buffer).map_next(BoxedParser::new)
+    }
+
+    fn finalize_boxed(
+        self: Box<Self>,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<P::Output>, P::Error> {
+        (*self).finalize_with(ctx, buffer)
+    }
+
+    fn finalize_consumed_boxed(
+        self: Box<Self>,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, P::Output)>, P::Error>
+    where
+        B: BufRef,
+    {
+        (*self).finalize_consumed(ctx, buffer)
+    }
+}
+
+/// A type-erased parser with input `B`, output `O`, and custom error `E`
+///
+/// Boxing hides deeply nested combinator types, so that parsers may be stored in struct fields or collections, returned from trait methods, or assembled at runtime. Only [Clone] parsers may be boxed, so that a [BoxedParser] is itself [Clone], including partway through parsing.
+pub struct BoxedParser<'a, B, O, E>(Box<dyn DynParserCore<'a, B, O, E> + 'a>)
+where
+    B: ?Sized;
+
+impl<'a, B, O, E> BoxedParser<'a, B, O, E>
+where
+    B: ?Sized,
+{
+    /// Box `parser`, erasing its concrete type
+    pub fn new<P>(parser: P) -> Self
+    where
+        P: ParserCore<B, Output = O, Error = E> + Clone + 'a,
+    {
+        BoxedParser(Box::new(parser))
+    }
+}
+
+impl<B, O, E> Clone for BoxedParser<'_, B, O, E>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        self.0.clone_boxed()
+    }
+}
+
+impl<B, O, E> fmt::Debug for BoxedParser<'_, B, O, E>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedParser").finish_non_exhaustive()
+    }
+}
+
+impl<B, O, E> ParserCore<B> for BoxedParser<'_, B, O, E>
+where
+    B: ?Sized,
+{
+    type Output = O;
+    type Error = E;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.0.feed_boxed(ctx, buffer)
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.0.finalize_boxed(ctx, buffer)
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        self.0.finalize_consumed_boxed(ctx, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use std::convert::Infallible;
+use std::ops::Range;
+
+use either::Either;
+use test_case::test_case;
+
+use crate::error::ParseResult;
+use crate::parser::Outcome::Parsed;
+use crate::parser::{BoxedParser, ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::literal;
+
+type Greeting<'a> = BoxedParser<'a, str, (&'a str, &'a str), Either<Infallible, Infallible>>;
+
+#[test_case(
+    literal("Hello").boxed().then(literal(" World")).boxed()
+    ; "boxed_then_unboxed"
+)]
+#[test_case(
+    literal("Hello").then(literal(" World").boxed()).boxed()
+    ; "unboxed_then_boxed"
+)]
+fn mixed_then(parser: Greeting<'_>) {
+    let update = parser.feed("Hello World!").unwrap();
+
+    assert_eq!(update.consumed, 11);
+    assert!(matches!(update.outcome, Parsed(("Hello", " World"))));
+}
+
+/// Build an alternation of keywords at runtime, yielding the index of the match
+fn keyword_index<'a>(keywords: &[&'a str]) -> BoxedParser<'a, str, usize, Infallible> {
+    keywords
+        .iter()
+        .enumerate()
+        .map(|(i, &kw)| literal(kw).map_output(move |_| i).boxed())
+        .reduce(|acc, p| acc.or(p).map_output(Either::into_inner).boxed())
+        .unwrap()
+}
+
+#[test_case("kw:let" => Ok((3..6, 0)) ; "first")]
+#[test_case("kw:loop" => Ok((3..7, 2)) ; "last")]
+#[test_case("kw:in" => Ok((3..5, 1)) ; "middle")]
+#[test_case("kw:for" => matches Err(_) ; "missing")]
+fn runtime_keywords(input: &str) -> ParseResult<(Range<usize>, usize), ()> {
+    const KEYWORDS: &[&str] = &["let", "in", "loop"];
+
+    let parse = |bufsize| {
+        literal("kw:")
+            .then(keyword_index(KEYWORDS).with_span())
+            .into_utf8_parser()
+            .parse_reader_with_bufsize::<_, Infallible>(input.as_bytes(), bufsize)
+            .map(|(_, spanned)| spanned)
+            .map_err(|e| e.map_custom(|_| ()))
+    };
+
+    let result = parse(1 << 14);
+    for bufsize in [1, 2] {
+        assert_eq!(parse(bufsize), result, "bufsize {bufsize}");
+    }
+    result
+}
+
+#[test]
+fn stored_in_collection() {
+    let parsers: Vec<BoxedParser<'_, [u8], usize, Infallible>> = vec![
+        literal(b"ab".as_slice()).map_output(|b| b.len()).boxed(),
+        literal(b"abcd".as_slice())
+            .peek()
+            .map_output(|b| b.len())
+            .boxed(),
+    ];
+
+    let outputs: Vec<_> = parsers
+        .into_iter()
+        .map(|p| p.feed(b"abcd".as_slice()).map(|up| up.map_next(|_| ())))
+        .collect();
+
+    assert_eq!(
+        outputs,
+        vec![
+            Ok(Update {
+                consumed: 2,
+                outcome: Parsed(2)
+            }),
+            Ok(Update {
+                consumed: 0,
+                outcome: Parsed(4)
+            }),
+        ]
+    );
+}
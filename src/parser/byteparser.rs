@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::io::Read;
 
 use either::Either;
@@ -5,7 +6,7 @@ use either::Either;
 use crate::buffer::BufferManager;
 use crate::error::{ParseResult, ParseResultExt};
 use crate::parser::Outcome::{Next, Parsed};
-use crate::parser::ParserCore;
+use crate::parser::{DecodeWith, ParserCore, Transducer};
 
 /// A consumer interface that can parse any sync I/O [std::io::Read] type
 ///
@@ -32,6 +33,42 @@ pub trait ByteParser: ParserCore<[u8]> {
     ) -> ParseResult<Self::Output, Either<Self::Error, std::io::Error>>
     where
         R: Read;
+
+    /// Read `r` to end of file and parse it using a buffer with a default size, giving parsers access to the user `state`
+    ///
+    /// See [Context](crate::parser::Context) for how parsers access the state.
+    fn parse_reader_with_state<R, S>(
+        self,
+        r: R,
+        state: &mut S,
+    ) -> ParseResult<Self::Output, Either<Self::Error, std::io::Error>>
+    where
+        R: Read,
+        S: Any,
+    {
+        const BUFSIZE: usize = 1 << 16;
+
+        self.parse_reader_with_bufsize_and_state(r, BUFSIZE, state)
+    }
+
+    /// Read `r` to end of file and parse it using a buffer with the allocated size, giving parsers access to the user `state`
+    fn parse_reader_with_bufsize_and_state<R, S>(
+        self,
+        r: R,
+        bufsize: usize,
+        state: &mut S,
+    ) -> ParseResult<Self::Output, Either<Self::Error, std::io::Error>>
+    where
+        R: Read,
+        S: Any;
+
+    /// Convert this parser to one that parses the byte stream transformed by `transducer`, such as a decoded base64 body
+    fn decode_with<T>(self, transducer: T) -> DecodeWith<T, Self>
+    where
+        T: Transducer,
+    {
+        DecodeWith::new(transducer, self)
+    }
 }
 
 impl<T> ByteParser for T
@@ -42,27 +79,58 @@ where
 {
     fn parse_reader_with_bufsize<R, E>(
         self,
-        mut r: R,
+        r: R,
+        bufsize: usize,
+    ) -> ParseResult<Self::Output, Either<Self::Error, std::io::Error>>
+    where
+        R: Read,
+    {
+        read_to_end(self, r, bufsize, None)
+    }
+
+    fn parse_reader_with_bufsize_and_state<R, S>(
+        self,
+        r: R,
         bufsize: usize,
+        state: &mut S,
     ) -> ParseResult<Self::Output, Either<Self::Error, std::io::Error>>
     where
         R: Read,
+        S: Any,
     {
-        use Either::{Left, Right};
+        read_to_end(self, r, bufsize, Some(state))
+    }
+}
 
-        let mut parser = self;
-        let mut bufmgr = BufferManager::from(vec![0u8; bufsize]);
+fn read_to_end<P, R>(
+    parser: P,
+    mut r: R,
+    bufsize: usize,
+    mut state: Option<&mut dyn Any>,
+) -> ParseResult<P::Output, Either<P::Error, std::io::Error>>
+where
+    P: ParserCore<[u8]> + std::fmt::Debug,
+    P::Output: std::fmt::Debug,
+    P::Error: std::fmt::Debug,
+    R: Read,
+{
+    use Either::{Left, Right};
 
-        loop {
-            let writeslice = bufmgr.get_write_slice();
-            let readcnt = r.read(writeslice).map_err(Right)?;
-            match bufmgr.process_write(parser, readcnt).map_err_custom(Left)? {
-                Next(next) => {
-                    parser = next;
-                }
-                Parsed(output) => {
-                    return Ok(output);
-                }
+    let mut parser = parser;
+    let mut bufmgr = BufferManager::from(vec![0u8; bufsize]);
+
+    loop {
+        let writeslice = bufmgr.get_write_slice();
+        let readcnt = r.read(writeslice).map_err(Right)?;
+        match bufmgr
+            .process_write_in(parser, readcnt, state.as_deref_mut())
+            .map_err_custom(Left)?
+        {
+            Next(next) => {
+                parser = next;
+            }
+            Parsed(output) => {
+                return Ok(output);
             }
         }
     }
@@ -0,0 +1,86 @@
+use std::any::Any;
+use std::fmt;
+
+#[cfg(doc)]
+use crate::{
+    buffer::BufferManager,
+    parser::{ByteParser, ParserCore, PushParser},
+    primitive::with_state,
+};
+
+/// The context in which a parser is fed input: its absolute stream position and optional per-parse user state
+///
+/// Drivers such as [BufferManager] and [ByteParser::parse_reader_with_state] construct a [Context] for each call to [ParserCore::feed_with] or [ParserCore::finalize_with], and combinators pass it along to their subparsers, advancing the offset past any input consumed in between. The user state is shared mutable data, such as a string interner, a symbol table, or an indentation stack, which parsers access with [Context::state], [with_state], or [PushParser::map_with_state]. The state is recovered by its type through [Any], so it must be `'static` and cannot borrow from its caller.
+#[derive(Default)]
+pub struct Context<'s> {
+    offset: usize,
+    state: Option<&'s mut dyn Any>,
+}
+
+impl<'s> Context<'s> {
+    /// A context without user state, where input begins at absolute stream position `offset`
+    pub fn new(offset: usize) -> Self {
+        Context {
+            offset,
+            state: None,
+        }
+    }
+
+    /// A context with user `state`, where input begins at absolute stream position `offset`
+    pub fn with_state<S>(offset: usize, state: &'s mut S) -> Self
+    where
+        S: Any,
+    {
+        Context {
+            offset,
+            state: Some(state),
+        }
+    }
+
+    pub(crate) fn from_parts(offset: usize, state: Option<&'s mut dyn Any>) -> Self {
+        Context { offset, state }
+    }
+
+    /// The absolute stream position of the beginning of the input
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The user state, if present and of type `S`
+    pub fn state<S>(&mut self) -> Option<&mut S>
+    where
+        S: Any,
+    {
+        self.state.as_deref_mut()?.downcast_mut()
+    }
+
+    /// Borrow this context for passing to a subparser, retaining it for later use
+    pub fn reborrow(&mut self) -> Context<'_> {
+        self.advance(0)
+    }
+
+    /// Borrow this context for input which begins `consumed` bytes later
+    pub fn advance(&mut self, consumed: usize) -> Context<'_> {
+        Context {
+            offset: self.offset + consumed,
+            state: self.state.as_deref_mut(),
+        }
+    }
+
+    /// Borrow this context for a different input stream, such as tokens, which begins at position `offset`
+    pub(crate) fn at(&mut self, offset: usize) -> Context<'_> {
+        Context {
+            offset,
+            state: self.state.as_deref_mut(),
+        }
+    }
+}
+
+impl fmt::Debug for Context<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("offset", &self.offset)
+            .field("state", &self.state.as_ref().map(|_| "..."))
+            .finish()
+    }
+}
@@ -1,8 +1,15 @@
+use crate::buffer::BufRef;
 use crate::error::ParseResult;
-use crate::parser::Update;
+use crate::parser::{Context, Update};
 
 #[cfg(doc)]
-use crate::parser::PushParser;
+use crate::{
+    buffer::BufferManager,
+    combinator::{Then, WithSpan},
+    error::ParseError,
+    parser::PushParser,
+    primitive::Literal,
+};
 
 /// The core parser functionality which must be implemented for new parsers
 ///
@@ -23,4 +30,51 @@ where
     ///
     /// Some parsers produce a value only upon end-of-input, for example the parser that matches any number of `'x'` chars in a string.
     fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error>;
+
+    /// Feed some input in a [Context] giving its absolute stream position and any per-parse user state
+    ///
+    /// Drivers which track the stream position or hold user state, such as [BufferManager], call this rather than [ParserCore::feed]. Combinators override it to forward the context to each subparser, advanced past any input consumed in between, so that context-aware parsers, such as [WithSpan] and [PushParser::map_with_state], observe absolute offsets and the shared state. The default implementation ignores `ctx`, so a parser which wraps subparsers must override this and [ParserCore::finalize_with] to forward it; otherwise its subparsers see offset 0 and no user state, and parsers which require state fail with [ParseError::MissingState].
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        let _ = ctx;
+        self.feed(buffer)
+    }
+
+    /// Indicate the end of input in a [Context]
+    ///
+    /// This is the [ParserCore::finalize] counterpart of [ParserCore::feed_with].
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let _ = ctx;
+        self.finalize(buffer)
+    }
+
+    /// Indicate the end of input in a [Context], also yielding how much of `buffer` the parser consumed
+    ///
+    /// Sequencing combinators such as [Then] finalize their next subparser on the input which is left over, which is not empty after lookahead such as [PushParser::peek]. The default implementation first feeds `buffer`, so a parser which stops before its end, such as a [Literal] matching a prefix, reports what it matched; otherwise the parser is finalized on the rest, and all of `buffer` is reported as consumed. Parsers which can report their consumption directly override it.
+    fn finalize_consumed(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        // The buffer may hold input not yet fed, such as when an alternative is finalized:
+        let Update { consumed, outcome } = self.feed_with(ctx.reborrow(), buffer)?;
+        match outcome {
+            Parsed(output) => Ok(Some((consumed, output))),
+            Next(parser) => Ok(parser
+                .finalize_with(ctx.advance(consumed), buffer.drop_up_to(consumed))?
+                .map(|output| (buffer.len(), output))),
+        }
+    }
 }
@@ -0,0 +1,144 @@
+use either::Either::{self, Left, Right};
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::{ParseResult, ParseResultExt};
+use crate::parser::{Context, Downstream, ParserCore, Transducer, Update};
+
+/// Wrap a `[u8]` parser `P` to parse the byte stream transformed by [Transducer] `T`
+///
+/// Transformed bytes are buffered internally until `P` consumes them. When the transformed stream ends, `P` is finalized, and any later input remains unconsumed. Input is consumed a transducer step at a time: the input behind transformed bytes which `P` does not consume remains unconsumed, and if `P` stops partway through the bytes of a single step, the parse fails with [UnexpectedInput](crate::error::ParseError::UnexpectedInput). As with [Pipe](crate::sequence::Pipe), a custom error of the upstream stage `T` is [Left] and one of `P` is [Right].
+///
+/// `P` observes positions in the transformed stream via [Context::offset] rather than positions in the input.
+#[derive(Clone, Debug)]
+pub struct DecodeWith<T, P> {
+    transducer: Option<T>,
+    downstream: Downstream<P, u8>,
+}
+
+impl<T, P> DecodeWith<T, P> {
+    /// Create a new parser of `parser` over the output of `transducer`
+    pub fn new(transducer: T, parser: P) -> Self {
+        DecodeWith {
+            transducer: Some(transducer),
+            downstream: Downstream::new(parser),
+        }
+    }
+
+    /// Transform a prefix of `buffer` after the input already transformed, returning whether any fresh bytes were decoded
+    fn decode(&mut self, buffer: &[u8]) -> ParseResult<bool, T::Error>
+    where
+        T: Transducer,
+    {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let Some(transducer) = self.transducer.take() else {
+            return Ok(false);
+        };
+
+        let start = self.downstream.lookahead();
+        let decoded = self.downstream.items();
+        let declen = decoded.len();
+        let Update { consumed, outcome } = transducer.feed(buffer.drop_up_to(start), decoded)?;
+        let fresh = decoded.len() > declen;
+        self.downstream.advance(consumed);
+        match outcome {
+            Next(transducer) => self.transducer = Some(transducer),
+            Parsed(()) => {}
+        }
+        if fresh || self.transducer.is_none() {
+            self.downstream.mark();
+        }
+        Ok(fresh)
+    }
+}
+
+impl<T, P> ParserCore<[u8]> for DecodeWith<T, P>
+where
+    T: Transducer,
+    P: ParserCore<[u8]>,
+{
+    type Output = P::Output;
+    type Error = Either<T::Error, P::Error>;
+
+    fn feed(self, buffer: &[u8]) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &[u8]) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        mut self,
+        ctx: Context<'_>,
+        buffer: &[u8],
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let fresh = self.decode(buffer).map_err_custom(Left)?;
+
+        if self.transducer.is_none() {
+            // The transformed stream has ended:
+            let (consumed, output) = self
+                .downstream
+                .finish(ctx)
+                .map_err_custom(Right)?
+                .ok_or(ExpectedMoreInput)?;
+            return Ok(Update {
+                consumed,
+                outcome: Parsed(output),
+            });
+        }
+
+        if !fresh {
+            // The parser has no new bytes to see:
+            return Ok(Update {
+                consumed: 0,
+                outcome: Next(self),
+            });
+        }
+
+        let DecodeWith {
+            transducer,
+            downstream,
+        } = self;
+        downstream.feed(ctx).map_err_custom(Right).map(|up| {
+            up.map_next(|downstream| DecodeWith {
+                transducer,
+                downstream,
+            })
+        })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &[u8],
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_consumed(ctx, buffer)
+            .map(|optout| optout.map(|(_, output)| output))
+    }
+
+    fn finalize_consumed(
+        mut self,
+        ctx: Context<'_>,
+        buffer: &[u8],
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        self.decode(buffer).map_err_custom(Left)?;
+
+        if let Some(transducer) = self.transducer.take() {
+            let rest = buffer.drop_up_to(self.downstream.lookahead());
+            transducer
+                .finalize(rest, self.downstream.items())
+                .map_err_custom(Left)?;
+            self.downstream.advance(rest.len());
+            self.downstream.mark();
+        }
+
+        self.downstream.finish(ctx).map_err_custom(Right)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,196 @@
+use std::convert::Infallible;
+
+use either::Either::{self, Left, Right};
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Transducer, Update};
+use crate::primitive::{any, literal, position, uint, NumberError};
+use crate::sequence::SequenceParser;
+
+/// Strip backslash escapes, so that `\x` becomes `x`
+#[derive(Debug)]
+struct Unescape;
+
+impl Transducer for Unescape {
+    type Error = Infallible;
+
+    fn feed(
+        self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> ParseResult<Update<Self, ()>, Self::Error> {
+        let mut consumed = 0;
+        while let Some(&b) = input.get(consumed) {
+            if b != b'\\' {
+                output.push(b);
+                consumed += 1;
+            } else if let Some(&escaped) = input.get(consumed + 1) {
+                output.push(escaped);
+                consumed += 2;
+            } else {
+                // Retain a trailing backslash until the escaped byte arrives:
+                break;
+            }
+        }
+        Ok(Update {
+            consumed,
+            outcome: Next(self),
+        })
+    }
+
+    fn finalize(self, input: &[u8], output: &mut Vec<u8>) -> ParseResult<(), Self::Error> {
+        let Update { consumed, .. } = self.feed(input, output)?;
+        if consumed < input.len() {
+            Err(ExpectedMoreInput)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Un-chunk chunks of a length digit followed by that many bytes, ending at a `0` chunk
+#[derive(Debug, Default)]
+struct Chunked {
+    remaining: usize,
+}
+
+impl Transducer for Chunked {
+    type Error = Infallible;
+
+    fn feed(
+        mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> ParseResult<Update<Self, ()>, Self::Error> {
+        let mut consumed = 0;
+        loop {
+            let rest = &input[consumed..];
+            if self.remaining > 0 {
+                let len = std::cmp::min(self.remaining, rest.len());
+                output.extend_from_slice(&rest[..len]);
+                consumed += len;
+                self.remaining -= len;
+                if self.remaining > 0 {
+                    break;
+                }
+            } else {
+                match rest.first() {
+                    None => break,
+                    Some(b'0') => {
+                        return Ok(Update {
+                            consumed: consumed + 1,
+                            outcome: Parsed(()),
+                        })
+                    }
+                    Some(d @ b'1'..=b'9') => {
+                        self.remaining = usize::from(d - b'0');
+                        consumed += 1;
+                    }
+                    Some(_) => return Err(UnexpectedInput),
+                }
+            }
+        }
+        Ok(Update {
+            consumed,
+            outcome: Next(self),
+        })
+    }
+
+    fn finalize(self, input: &[u8], output: &mut Vec<u8>) -> ParseResult<(), Self::Error> {
+        match self.feed(input, output)?.outcome {
+            Parsed(()) => Ok(()),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+fn bytes() -> impl ParserCore<[u8], Output = Vec<u8>, Error = Infallible> + std::fmt::Debug {
+    any().repeated().collect()
+}
+
+type BytesResult = ParseResult<Option<Vec<u8>>, Either<Infallible, Infallible>>;
+
+#[test_case(br"a\,b\\c" => Ok(Some(br"a,b\c".to_vec())) ; "escapes")]
+#[test_case(b"" => Ok(Some(vec![])) ; "empty")]
+#[test_case(br"ab\" => Err(ExpectedMoreInput) ; "trailing_backslash")]
+fn finalize_unescape(input: &[u8]) -> BytesResult {
+    bytes().decode_with(Unescape).finalize(input)
+}
+
+#[test_case(b"5hello6 world0" => Ok(Some(b"hello world".to_vec())) ; "chunks")]
+#[test_case(b"0" => Ok(Some(vec![])) ; "no_chunks")]
+#[test_case(b"5hel" => Err(ExpectedMoreInput) ; "incomplete_chunk")]
+#[test_case(b"5hello" => Err(ExpectedMoreInput) ; "missing_end")]
+#[test_case(b"x" => Err(UnexpectedInput) ; "bad_length")]
+fn finalize_chunked(input: &[u8]) -> BytesResult {
+    bytes().decode_with(Chunked::default()).finalize(input)
+}
+
+#[test]
+fn ends_before_other_input() {
+    let Update { consumed, outcome } = bytes()
+        .decode_with(Chunked::default())
+        .feed(b"2hi3 yo0rest")
+        .unwrap();
+
+    assert_eq!(consumed, 8);
+    assert!(matches!(outcome, Parsed(b) if b == b"hi yo"));
+}
+
+type LiteralResult<'a> = ParseResult<Update<(), &'a [u8]>, Either<Infallible, Infallible>>;
+
+#[test_case(b"2hi3 yo" => matches Err(UnexpectedInput) ; "within_step")]
+#[test_case(b"2hi" => matches Ok(Update { consumed: 3, outcome: Parsed(b"hi") }) ; "at_step_end")]
+#[test_case(b"2h" => matches Ok(Update { consumed: 2, outcome: Next(()) }) ; "consumed_step")]
+fn parser_ends_before_stream(input: &[u8]) -> LiteralResult<'_> {
+    literal(b"hi".as_slice())
+        .decode_with(Chunked::default())
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn split_feeds(bufsize: usize) {
+    let output = uint::<u32, _>()
+        .into_utf8_parser()
+        .decode_with(Chunked::default())
+        .parse_reader_with_bufsize::<_, Infallible>(b"21233442670".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(output, 1234467);
+}
+
+#[test]
+fn parser_error() {
+    let result = uint::<u8, _>()
+        .into_utf8_parser()
+        .decode_with(Unescape)
+        .finalize(br"3\00");
+
+    assert_eq!(result, Err(Custom(Right(Left(NumberError::Overflow)))));
+}
+
+#[test]
+fn transducer_error() {
+    let result: ParseResult<_, Either<Infallible, Infallible>> = literal(b"ab".as_slice())
+        .decode_with(Chunked::default())
+        .finalize(b"2ab?");
+
+    assert_eq!(result, Err(UnexpectedInput));
+}
+
+#[test]
+fn decoded_positions() {
+    let result = literal(br"a\b".as_slice())
+        .then(position())
+        .decode_with(Unescape)
+        .finalize(br"a\\b");
+
+    assert_eq!(result, Ok(Some((br"a\b".as_slice(), 3))));
+}
@@ -0,0 +1,133 @@
+use crate::buffer::BufRef;
+use crate::error::ParseError::UnexpectedInput;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+#[cfg(doc)]
+use crate::{parser::DecodeWith, sequence::Pipe};
+
+/// A parser `P` over an intermediate stream of `T`, such as the tokens of a [Pipe] or the transformed bytes of a [DecodeWith]
+///
+/// The upstream stage appends items to [Downstream::items], which are buffered until `P` consumes them, and marks where in its input they end. Upstream input is reported consumed only up to the last mark whose items `P` has consumed, so the input behind items which `P` leaves unconsumed remains for whatever parser follows. The upstream stage resumes after the input it has already seen, at [Downstream::lookahead]. `P` observes positions in the intermediate stream via [Context::offset].
+#[derive(Clone, Debug)]
+pub(crate) struct Downstream<P, T> {
+    parser: P,
+    queue: Queue<T>,
+}
+
+#[derive(Clone, Debug)]
+struct Queue<T> {
+    items: Vec<T>,
+    pos: usize,
+    /// The number of buffered items at each mark, and the length of upstream input, from the start of the next buffer, which they end at
+    marks: Vec<(usize, usize)>,
+    lookahead: usize,
+}
+
+impl<P, T> Downstream<P, T> {
+    pub(crate) fn new(parser: P) -> Self {
+        Downstream {
+            parser,
+            queue: Queue {
+                items: vec![],
+                pos: 0,
+                marks: vec![],
+                lookahead: 0,
+            },
+        }
+    }
+
+    /// The length of input at the start of the next buffer which the upstream stage has already consumed
+    pub(crate) fn lookahead(&self) -> usize {
+        self.queue.lookahead
+    }
+
+    /// Record that the upstream stage consumed `consumed` more units of input
+    pub(crate) fn advance(&mut self, consumed: usize) {
+        self.queue.lookahead += consumed;
+    }
+
+    /// The buffer of items which `P` has not yet consumed
+    pub(crate) fn items(&mut self) -> &mut Vec<T> {
+        &mut self.queue.items
+    }
+
+    /// Record that the buffered items end where the upstream stage has consumed up to
+    pub(crate) fn mark(&mut self) {
+        let Queue {
+            items,
+            marks,
+            lookahead,
+            ..
+        } = &mut self.queue;
+        marks.push((items.len(), *lookahead));
+    }
+
+    /// Buffer an `item` which ends where the upstream stage has consumed up to
+    pub(crate) fn push(&mut self, item: T) {
+        self.queue.items.push(item);
+        self.mark();
+    }
+}
+
+impl<P, T> Downstream<P, T>
+where
+    P: ParserCore<[T]>,
+    [T]: BufRef,
+{
+    /// Feed `P` the buffered items, yielding the length of upstream input behind those it consumes
+    pub(crate) fn feed(
+        self,
+        mut ctx: Context<'_>,
+    ) -> ParseResult<Update<Self, P::Output>, P::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        let Downstream { parser, mut queue } = self;
+        let Update { consumed, outcome } =
+            parser.feed_with(ctx.at(queue.pos), queue.items.as_slice())?;
+        let consumed = queue.release(consumed, matches!(outcome, Parsed(_)))?;
+
+        Ok(Update {
+            consumed,
+            outcome: outcome.map_next(|parser| Downstream { parser, queue }),
+        })
+    }
+
+    /// Finish `P` at the end of the intermediate stream, yielding the length of upstream input behind the items it consumes
+    pub(crate) fn finish(
+        self,
+        mut ctx: Context<'_>,
+    ) -> ParseResult<Option<(usize, P::Output)>, P::Error> {
+        let Downstream { parser, mut queue } = self;
+        match parser.finalize_consumed(ctx.at(queue.pos), queue.items.as_slice())? {
+            Some((consumed, output)) => Ok(Some((queue.release(consumed, true)?, output))),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T> Queue<T> {
+    /// Discard the first `consumed` items, returning the length of upstream input up to the last mark they cover
+    ///
+    /// Once `P` has parsed, consumed items past that mark would be lost along with the rest of its upstream input, so this is an [UnexpectedInput] error.
+    fn release<E>(&mut self, consumed: usize, parsed: bool) -> ParseResult<usize, E> {
+        let covered = self.marks.partition_point(|&(count, _)| count <= consumed);
+        let (count, upstream) = match covered.checked_sub(1) {
+            Some(last) => self.marks[last],
+            None => (0, 0),
+        };
+        if parsed && count < consumed {
+            return Err(UnexpectedInput);
+        }
+
+        self.items.drain(..consumed);
+        self.marks.drain(..covered);
+        for (count, end) in &mut self.marks {
+            *count -= consumed;
+            *end -= upstream;
+        }
+        self.pos += consumed;
+        self.lookahead -= upstream;
+        Ok(upstream)
+    }
+}
@@ -3,10 +3,10 @@ use std::str::Utf8Error;
 use either::Either::{self, Left, Right};
 
 use crate::error::{ParseResult, ParseResultExt, ParseResultUpdateExt};
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 
 /// Wrap any [str] parser into a UTF-8 `[u8]` parser
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct IntoUtf8Parser<P>(P);
 
 impl<P> From<P> for IntoUtf8Parser<P> {
@@ -23,16 +23,35 @@ where
     type Error = Either<P::Error, Utf8Error>;
 
     fn feed(self, buffer: &[u8]) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
-        let (s, _) = from_utf8_partial(buffer).map_err(Right)?;
-        self.0.feed(s).map_next(IntoUtf8Parser).map_err_custom(Left)
+        self.feed_with(Context::default(), buffer)
     }
 
     fn finalize(self, buffer: &[u8]) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &[u8],
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        let (s, _) = from_utf8_partial(buffer).map_err(Right)?;
+        self.0
+            .feed_with(ctx, s)
+            .map_next(IntoUtf8Parser)
+            .map_err_custom(Left)
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &[u8],
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
         use crate::error::ParseError::ExpectedMoreInput;
 
         let (s, noise) = from_utf8_partial(buffer).map_err(Right)?;
         if noise.is_empty() {
-            self.0.finalize(s).map_err_custom(Left)
+            self.0.finalize_with(ctx, s).map_err_custom(Left)
         } else {
             Err(ExpectedMoreInput)
         }
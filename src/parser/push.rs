@@ -1,6 +1,8 @@
 use crate::buffer::BufRef;
-use crate::combinator::{MapOutput, Optional, Or, Repeated, Then};
-use crate::parser::ParserCore;
+use crate::combinator::{
+    AndThen, MapError, MapOutput, MapWithState, Not, Optional, Or, Peek, Repeated, Then, WithSpan,
+};
+use crate::parser::{BoxedParser, ParserCore};
 
 /// The primary composition interface for push parsers
 pub trait PushParser<B>: ParserCore<B>
@@ -15,6 +17,25 @@ where
         MapOutput::new(self, f)
     }
 
+    /// Convert this output once parsed, with mutable access to the per-parse user state `S`
+    ///
+    /// The state is supplied by a driver such as [ByteParser::parse_reader_with_state](crate::parser::ByteParser::parse_reader_with_state); see [MapWithState] for details.
+    fn map_with_state<S, F, O>(self, f: F) -> MapWithState<Self, F, S, O, B>
+    where
+        F: FnOnce(&mut S, Self::Output) -> O,
+        S: std::any::Any,
+    {
+        MapWithState::new(self, f)
+    }
+
+    /// Convert this custom error if one occurs
+    fn map_error<F, E>(self, f: F) -> MapError<Self, F, E, B>
+    where
+        F: FnOnce(Self::Error) -> E,
+    {
+        MapError::new(self, f)
+    }
+
     /// Parse `self` then `next` in sequence, yielding `(Self::Output, P::Output)`
     fn then<P>(self, next: P) -> Then<Self, P, B>
     where
@@ -23,6 +44,15 @@ where
         Then::new(self, next)
     }
 
+    /// Parse `self`, then the parser which `f` builds from its output, yielding `P::Output`
+    fn and_then<F, P>(self, f: F) -> AndThen<Self, F, P, B>
+    where
+        F: FnOnce(Self::Output) -> P,
+        P: ParserCore<B>,
+    {
+        AndThen::new(self, f)
+    }
+
     /// Parse either `self` or `alternative`, yielding `Either<Self::Output, P::Output>`
     fn or<P>(self, alternative: P) -> Or<Self, P>
     where
@@ -39,6 +69,22 @@ where
         Optional::from(self)
     }
 
+    /// Parse `self` as lookahead, yielding `Self::Output` without consuming input
+    fn peek(self) -> Peek<Self>
+    where
+        B: BufRef,
+    {
+        Peek::from(self)
+    }
+
+    /// Succeed without consuming input only if `self` fails to parse, yielding `()`
+    fn not(self) -> Not<Self>
+    where
+        B: BufRef,
+    {
+        Not::from(self)
+    }
+
     /// Parse `self` multiple times
     fn repeated(self) -> Repeated<Self>
     where
@@ -46,6 +92,19 @@ where
     {
         Repeated::from(self)
     }
+
+    /// Erase the type of `self` into a [BoxedParser]
+    fn boxed<'a>(self) -> BoxedParser<'a, B, Self::Output, Self::Error>
+    where
+        Self: Clone + 'a,
+    {
+        BoxedParser::new(self)
+    }
+
+    /// Yield the input range consumed by `self` alongside its output, `(Range<usize>, Self::Output)`
+    fn with_span(self) -> WithSpan<Self> {
+        WithSpan::from(self)
+    }
 }
 
 impl<B, P> PushParser<B> for P
@@ -0,0 +1,22 @@
+use crate::error::ParseResult;
+use crate::parser::Update;
+
+#[cfg(doc)]
+use crate::parser::{ByteParser, Outcome, ParserCore};
+
+/// A [Transducer] incrementally transforms one byte stream into another, such as by un-chunking an HTTP body, decoding base64, or stripping escapes
+///
+/// A transducer is fed input as a [ParserCore] is, under the same buffer invariants, but rather than parsing a value it appends transformed bytes to an `output` buffer. Its [Update] yields [Outcome::Parsed] when the transformed stream ends before the end of input, such as at the terminating chunk of a chunked body, leaving any later input unconsumed.
+///
+/// [ByteParser::decode_with] feeds the transformed stream into a parser.
+pub trait Transducer: Sized {
+    /// The custom error type
+    type Error;
+
+    /// Transform a prefix of `input`, appending the transformed bytes to `output`
+    fn feed(self, input: &[u8], output: &mut Vec<u8>)
+        -> ParseResult<Update<Self, ()>, Self::Error>;
+
+    /// Indicate the end of input, transforming the remaining `input`
+    fn finalize(self, input: &[u8], output: &mut Vec<u8>) -> ParseResult<(), Self::Error>;
+}
@@ -1,6 +1,51 @@
-//! Primitive parsers which are frequently used as building blocks for parser composition, such as [End]
+//! Primitive parsers which are frequently used as building blocks for parser composition, such as [End], [Literal], and [TakeWhile]
+mod empty;
 mod end;
+mod fail;
+mod fixedwidth;
+mod float;
+mod identifier;
+mod int;
+mod keywords;
 mod literal;
+mod literalnocase;
+mod literalowned;
+mod position;
+mod pure;
+mod quoted;
+mod satisfy;
+mod skip;
+mod take;
+mod takeuntil;
+mod takewhile;
+pub mod text;
+mod token;
+mod varint;
+mod withstate;
 
+pub use self::empty::{empty, Empty};
 pub use self::end::{end, End};
+pub use self::fail::{fail, Fail};
+pub use self::fixedwidth::{
+    f32_be, f32_le, f64_be, f64_le, i128_be, i128_le, i16_be, i16_le, i32_be, i32_le, i64_be,
+    i64_le, i8, u128_be, u128_le, u16_be, u16_le, u32_be, u32_le, u64_be, u64_le, u8, FixedWidth,
+    FixedWidthNumber,
+};
+pub use self::float::{float, Float, FloatingPoint};
+pub use self::identifier::{ascii_identifier, identifier, Identifier, IdentifierError};
+pub use self::int::{binary, hex, int, octal, uint, Int, Integer, NumberError};
+pub use self::keywords::{keywords, Keywords};
 pub use self::literal::{literal, Literal};
+pub use self::literalnocase::{literal_no_case, literal_no_case_unicode, CaseFold, LiteralNoCase};
+pub use self::literalowned::{literal_owned, LiteralOwned};
+pub use self::position::{position, Position};
+pub use self::pure::{pure, Pure};
+pub use self::quoted::{quoted_string, QuoteConfig, QuoteError, QuotedString};
+pub use self::satisfy::{any, none_of, one_of, satisfy, Satisfy};
+pub use self::skip::{skip, Skip};
+pub use self::take::{take, Take};
+pub use self::takeuntil::{take_until, TakeUntil};
+pub use self::takewhile::{take_while, take_while1, TakeWhile};
+pub use self::token::{satisfy_token, select, token, token_kind, Select};
+pub use self::varint::{sleb128, uleb128, zigzag, Leb128, VarintError};
+pub use self::withstate::{with_state, WithState};
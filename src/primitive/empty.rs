@@ -0,0 +1,54 @@
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::UnexpectedInput;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct an [Empty] parser which never succeeds
+pub fn empty<O>() -> Empty<O> {
+    Empty(PhantomData)
+}
+
+/// An [Empty] parser always errors with [UnexpectedInput] without consuming input
+///
+/// This is the neutral element of [Or](crate::combinator::Or), such as for a choice among no alternatives.
+#[derive(Debug)]
+pub struct Empty<O>(PhantomData<O>);
+
+impl<O> Clone for Empty<O> {
+    fn clone(&self) -> Self {
+        empty()
+    }
+}
+
+impl<B, O> ParserCore<B> for Empty<O>
+where
+    B: ?Sized,
+{
+    type Output = O;
+    type Error = Infallible;
+
+    fn feed(self, _: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        Err(UnexpectedInput)
+    }
+
+    fn finalize(self, _: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Err(UnexpectedInput)
+    }
+
+    fn finalize_consumed(
+        self,
+        _: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        Err(UnexpectedInput)
+    }
+}
+
+#[cfg(test)]
+mod tests;
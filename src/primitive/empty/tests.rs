@@ -0,0 +1,25 @@
+use std::convert::Infallible;
+
+use either::Either;
+
+use crate::error::ParseError::UnexpectedInput;
+use crate::parser::{ByteParser, ParserCore, PushParser};
+use crate::primitive::{empty, literal};
+
+#[test]
+fn empty_errors() {
+    assert_eq!(
+        ParserCore::<str>::finalize(empty::<()>(), ""),
+        Err(UnexpectedInput)
+    );
+}
+
+#[test]
+fn or_neutral() {
+    let parsed = empty::<()>()
+        .or(literal(b"a".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"a".as_slice(), 1)
+        .unwrap();
+
+    assert!(matches!(parsed, Either::Right(b"a")));
+}
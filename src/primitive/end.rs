@@ -10,7 +10,7 @@ pub fn end() -> End {
 }
 
 /// The [End] parser only succeeds on an empty end of input
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct End;
 
 impl<B> ParserCore<B> for End
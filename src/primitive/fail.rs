@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+
+use crate::error::ParseError::Custom;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Fail] parser which always errors with [Custom] `error`
+pub fn fail<O, E>(error: E) -> Fail<O, E> {
+    Fail {
+        error,
+        phantom: PhantomData,
+    }
+}
+
+/// A [Fail] parser always errors with [Custom] `E` without consuming input
+///
+/// The output type `O` is never produced, but allows a [Fail] to stand in for any parser, such as in [and_then](crate::parser::PushParser::and_then) to reject an earlier output.
+#[derive(Debug)]
+pub struct Fail<O, E> {
+    error: E,
+    phantom: PhantomData<O>,
+}
+
+impl<O, E> Clone for Fail<O, E>
+where
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        fail(self.error.clone())
+    }
+}
+
+impl<B, O, E> ParserCore<B> for Fail<O, E>
+where
+    B: ?Sized,
+{
+    type Output = O;
+    type Error = E;
+
+    fn feed(self, _: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        Err(Custom(self.error))
+    }
+
+    fn finalize(self, _: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Err(Custom(self.error))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+use std::convert::Infallible;
+
+use either::Either::{self, Left, Right};
+use test_case::test_case;
+
+use crate::error::ParseError::Custom;
+use crate::error::ParseResult;
+use crate::parser::{ByteParser, ParserCore, PushParser};
+use crate::primitive::{fail, pure, uint, NumberError};
+
+#[derive(Clone, Debug, PartialEq)]
+struct TooLarge(u32);
+
+#[test]
+fn fail_errors() {
+    let parsed: ParseResult<Option<()>, _> = ParserCore::<str>::finalize(fail(TooLarge(1)), "");
+    assert_eq!(parsed, Err(Custom(TooLarge(1))));
+}
+
+#[test_case(b"42;" => matches Ok(42) ; "accepted")]
+#[test_case(b"420;" => matches Err(Custom(Left(Right(TooLarge(420))))) ; "rejected")]
+fn and_then_validate(
+    input: &[u8],
+) -> ParseResult<u32, Either<Either<NumberError, TooLarge>, std::io::Error>> {
+    uint::<u32, _>()
+        .and_then(|n| {
+            if n <= 255 {
+                pure(n).map_error(|never| match never {}).boxed()
+            } else {
+                fail(TooLarge(n)).boxed()
+            }
+        })
+        .parse_reader_with_bufsize::<_, Infallible>(input, 1)
+}
@@ -0,0 +1,161 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+mod sealed {
+    /// Restricts [FixedWidthNumber](super::FixedWidthNumber) to the primitive numbers, which fit in a [FixedWidth](super::FixedWidth) buffer
+    pub trait Sealed {}
+}
+
+/// Numbers which a [FixedWidth] parser may decode from at most 16 bytes
+///
+/// This trait is sealed: it is implemented for the primitive integer and floating point types only.
+pub trait FixedWidthNumber: Copy + sealed::Sealed {
+    /// The encoded width in bytes
+    const WIDTH: usize;
+
+    /// Decode from the first [Self::WIDTH] of `bytes` in big or little endian order
+    fn decode(bytes: &[u8; 16], big_endian: bool) -> Self;
+}
+
+/// A [FixedWidth] parser decodes a fixed-width binary number from `[u8]` input
+///
+/// Bytes are copied into an internal array as they arrive, so a value may straddle any number of buffers without the [BufferManager](crate::buffer::BufferManager) retaining them.
+pub struct FixedWidth<T> {
+    bytes: [u8; 16],
+    filled: usize,
+    big_endian: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<T> FixedWidth<T>
+where
+    T: FixedWidthNumber,
+{
+    fn new(big_endian: bool) -> Self {
+        const { assert!(T::WIDTH <= 16, "FixedWidthNumber::WIDTH exceeds the buffer") };
+
+        FixedWidth {
+            bytes: [0; 16],
+            filled: 0,
+            big_endian,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for FixedWidth<T> {
+    fn clone(&self) -> Self {
+        FixedWidth {
+            bytes: self.bytes,
+            filled: self.filled,
+            big_endian: self.big_endian,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for FixedWidth<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedWidth")
+            .field("bytes", &&self.bytes[..self.filled])
+            .field("big_endian", &self.big_endian)
+            .finish()
+    }
+}
+
+impl<T> ParserCore<[u8]> for FixedWidth<T>
+where
+    T: FixedWidthNumber,
+{
+    type Output = T;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &[u8]) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let consumed = std::cmp::min(T::WIDTH - self.filled, buffer.len());
+        self.bytes[self.filled..self.filled + consumed].copy_from_slice(&buffer[..consumed]);
+        self.filled += consumed;
+
+        Ok(Update {
+            consumed,
+            outcome: if self.filled == T::WIDTH {
+                Parsed(T::decode(&self.bytes, self.big_endian))
+            } else {
+                Next(self)
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &[u8]) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        match self.feed(buffer)?.outcome {
+            Parsed(value) => Ok(Some(value)),
+            _ => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+macro_rules! fixed_width {
+    ( $t:ident ) => {
+        impl sealed::Sealed for $t {}
+
+        impl FixedWidthNumber for $t {
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            fn decode(bytes: &[u8; 16], big_endian: bool) -> Self {
+                let bytes = std::array::from_fn(|i| bytes[i]);
+                if big_endian {
+                    <$t>::from_be_bytes(bytes)
+                } else {
+                    <$t>::from_le_bytes(bytes)
+                }
+            }
+        }
+    };
+
+    ( $t:ident, $single:ident ) => {
+        fixed_width!($t);
+
+        #[doc = concat!("Construct a [FixedWidth] parser which yields a `", stringify!($t), "`")]
+        pub fn $single() -> FixedWidth<$t> {
+            FixedWidth::new(true)
+        }
+    };
+
+    ( $t:ident, $be:ident, $le:ident ) => {
+        fixed_width!($t);
+
+        #[doc = concat!("Construct a [FixedWidth] parser which yields a big-endian `", stringify!($t), "`")]
+        pub fn $be() -> FixedWidth<$t> {
+            FixedWidth::new(true)
+        }
+
+        #[doc = concat!("Construct a [FixedWidth] parser which yields a little-endian `", stringify!($t), "`")]
+        pub fn $le() -> FixedWidth<$t> {
+            FixedWidth::new(false)
+        }
+    };
+}
+
+fixed_width!(u8, u8);
+fixed_width!(i8, i8);
+fixed_width!(u16, u16_be, u16_le);
+fixed_width!(i16, i16_be, i16_le);
+fixed_width!(u32, u32_be, u32_le);
+fixed_width!(i32, i32_be, i32_le);
+fixed_width!(u64, u64_be, u64_le);
+fixed_width!(i64, i64_be, i64_le);
+fixed_width!(u128, u128_be, u128_le);
+fixed_width!(i128, i128_be, i128_le);
+fixed_width!(f32, f32_be, f32_le);
+fixed_width!(f64, f64_be, f64_le);
+
+#[cfg(test)]
+mod tests;
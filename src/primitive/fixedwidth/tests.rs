@@ -0,0 +1,78 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, Update};
+use crate::primitive::{
+    f32_be, f64_le, i32_be, i8, take, u16_be, u16_le, u32_be, u64_le, u8, FixedWidthNumber,
+};
+
+#[test_case(&[0x12, 0x34, 0x56] => Ok(Update { consumed: 2, outcome: Parsed(0x1234) }) ; "prefix")]
+#[test_case(&[0x12] => Ok(Update { consumed: 1, outcome: Next(()) }) ; "partial")]
+#[test_case(&[] => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn u16_be_feed(input: &[u8]) -> ParseResult<Update<(), u16>, Infallible> {
+    u16_be().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test]
+fn straddles_feeds() {
+    let Update { consumed, outcome } = u32_be().feed(&[0xde, 0xad]).unwrap();
+    assert_eq!(consumed, 2);
+
+    let Next(parser) = outcome else {
+        panic!("expected Next");
+    };
+    let Update { consumed, outcome } = parser.feed(&[0xbe]).unwrap();
+    assert_eq!(consumed, 1);
+
+    let Next(parser) = outcome else {
+        panic!("expected Next");
+    };
+    assert_eq!(parser.finalize(&[0xef]), Ok(Some(0xdeadbeef)));
+}
+
+#[test_case(&[0x01, 0x02] => Ok(Some(0x0201)) ; "exact")]
+#[test_case(&[0x01] => Err(ExpectedMoreInput) ; "short")]
+fn u16_le_finalize(input: &[u8]) -> ParseResult<Option<u16>, Infallible> {
+    u16_le().finalize(input)
+}
+
+#[test]
+fn single_bytes() {
+    assert_eq!(u8().finalize(&[0xff]), Ok(Some(0xff)));
+    assert_eq!(i8().finalize(&[0xff]), Ok(Some(-1)));
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn record(bufsize: usize) {
+    let mut input = vec![];
+    input.extend_from_slice(&(-2i32).to_be_bytes());
+    input.extend_from_slice(&0x0102030405060708u64.to_le_bytes());
+    input.extend_from_slice(&1.5f32.to_be_bytes());
+    input.extend_from_slice(&(-0.25f64).to_le_bytes());
+    input.extend_from_slice(&[3, b'a', b'b', b'c']);
+
+    let ((((a, b), c), d), name) = i32_be()
+        .then(u64_le())
+        .then(f32_be())
+        .then(f64_le())
+        .then(u8().then(take(3)))
+        .parse_reader_with_bufsize::<_, Infallible>(input.as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!((a, b, c, d), (-2, 0x0102030405060708, 1.5, -0.25));
+    assert_eq!(name, (3, b"abc".to_vec()));
+}
+
+#[test_case(true => 0x1234 ; "big_endian")]
+#[test_case(false => 0x3412 ; "little_endian")]
+fn decode_prefix(big_endian: bool) -> u16 {
+    let mut bytes = [0xff; 16];
+    bytes[..2].copy_from_slice(&[0x12, 0x34]);
+    u16::decode(&bytes, big_endian)
+}
@@ -0,0 +1,208 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Float] parser which yields a floating point number, such as `float::<f64>()`
+pub fn float<T, B>() -> Float<B, T>
+where
+    T: FloatingPoint,
+    B: ?Sized,
+{
+    Float {
+        state: State::Start,
+        acc: String::new(),
+        phantom: PhantomData,
+        output: PhantomData,
+    }
+}
+
+/// Floating point types which a [Float] parser may yield
+pub trait FloatingPoint: FromStr {}
+
+impl FloatingPoint for f32 {}
+impl FloatingPoint for f64 {}
+
+/// A [Float] parser yields a floating point number from `str` or `[u8]` input
+///
+/// The accepted syntax is that of [f64::from_str]: an optional sign followed by digits with an optional fraction and exponent, such as `-1.5e-3`, `.5`, or `1.`, or by `inf`, `infinity`, or `nan` in any case. Conversion is delegated to [FromStr], so results are correctly rounded.
+///
+/// The number ends at the last input which completes a valid number, so an incomplete suffix such as the `e` in `"1e"` is left unconsumed. Input beyond a valid number is retained until the number either extends or can't, so a complete number is only yielded once such input is fed or upon [ParserCore::finalize].
+pub struct Float<B, T>
+where
+    B: ?Sized,
+{
+    state: State,
+    acc: String,
+    phantom: PhantomData<B>,
+    output: PhantomData<T>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum State {
+    Start,
+    Sign,
+    Int,
+    LeadDot,
+    Frac,
+    Exp,
+    ExpSign,
+    ExpInt,
+    Word { word: &'static [u8], len: usize },
+}
+
+impl State {
+    fn step(self, byte: u8) -> Option<State> {
+        use State::*;
+
+        let next = match (self, byte) {
+            (Start, b'+' | b'-') => Sign,
+            (Start | Sign | Int, b'0'..=b'9') => Int,
+            (Start | Sign, b'.') => LeadDot,
+            (Int, b'.') => Frac,
+            (LeadDot | Frac, b'0'..=b'9') => Frac,
+            (Int | Frac, b'e' | b'E') => Exp,
+            (Exp, b'+' | b'-') => ExpSign,
+            (Exp | ExpSign | ExpInt, b'0'..=b'9') => ExpInt,
+            (Start | Sign, b'i' | b'I') => Word {
+                word: b"infinity",
+                len: 1,
+            },
+            (Start | Sign, b'n' | b'N') => Word {
+                word: b"nan",
+                len: 1,
+            },
+            (Word { word, len }, byte)
+                if word.get(len).is_some_and(|b| b.eq_ignore_ascii_case(&byte)) =>
+            {
+                Word { word, len: len + 1 }
+            }
+            _ => return None,
+        };
+        Some(next)
+    }
+
+    fn accepts(self) -> bool {
+        match self {
+            State::Int | State::Frac | State::ExpInt => true,
+            State::Word { word, len } => len == word.len() || (word == b"infinity" && len == 3),
+            _ => false,
+        }
+    }
+
+    fn is_final(self) -> bool {
+        matches!(self, State::Word { word, len } if len == word.len())
+    }
+}
+
+impl<B, T> Float<B, T>
+where
+    B: ?Sized,
+    T: FloatingPoint,
+{
+    /// Walk `bytes` from the current state, returning the state reached if a longer number remains possible, along with the length and state of the last accepting point
+    fn scan(&self, bytes: &[u8]) -> (Option<State>, Option<(usize, State)>) {
+        let mut state = self.state;
+        let mut last = None;
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            let Some(next) = state.step(byte) else {
+                return (None, last);
+            };
+            state = next;
+            if state.accepts() {
+                last = Some((i + 1, state));
+            }
+        }
+
+        ((!state.is_final()).then_some(state), last)
+    }
+
+    fn output(mut self, bytes: &[u8]) -> T {
+        // The grammar only accepts ASCII:
+        self.acc.extend(bytes.iter().copied().map(char::from));
+        self.acc
+            .parse()
+            .unwrap_or_else(|_| unreachable!("accepted syntax is valid for FromStr"))
+    }
+}
+
+impl<B, T> Clone for Float<B, T>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Float {
+            state: self.state,
+            acc: self.acc.clone(),
+            phantom: PhantomData,
+            output: PhantomData,
+        }
+    }
+}
+
+impl<B, T> fmt::Debug for Float<B, T>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Float")
+            .field("state", &self.state)
+            .field("acc", &self.acc)
+            .finish()
+    }
+}
+
+impl<B, T> ParserCore<B> for Float<B, T>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+    T: FloatingPoint,
+{
+    type Output = T;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let bytes = buffer.as_ref();
+        let pending = self.state.accepts();
+
+        let (consumed, outcome) = match self.scan(bytes) {
+            (None, Some((len, _))) => (len, Parsed(self.output(&bytes[..len]))),
+            (None, None) if pending => (0, Parsed(self.output(&[]))),
+            (None, None) => return Err(UnexpectedInput),
+            // Retain input beyond the last valid number in case it extends:
+            (Some(_), Some((len, state))) => {
+                self.acc
+                    .extend(bytes[..len].iter().copied().map(char::from));
+                (len, Next(Float { state, ..self }))
+            }
+            (Some(_), None) if pending => (0, Next(self)),
+            (Some(state), None) => {
+                self.acc.extend(bytes.iter().copied().map(char::from));
+                (bytes.len(), Next(Float { state, ..self }))
+            }
+        };
+
+        Ok(Update { consumed, outcome })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let bytes = buffer.as_ref();
+
+        match self.scan(bytes) {
+            (_, Some((len, _))) => Ok(Some(self.output(&bytes[..len]))),
+            _ if self.state.accepts() => Ok(Some(self.output(&[]))),
+            (Some(_), None) => Err(ExpectedMoreInput),
+            (None, None) => Err(UnexpectedInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
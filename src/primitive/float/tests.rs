@@ -0,0 +1,84 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{float, literal};
+
+#[test_case("1.5;" => Ok(Update { consumed: 3, outcome: Parsed(1.5) }) ; "terminated")]
+#[test_case("1.5" => Ok(Update { consumed: 3, outcome: Next(()) }) ; "undecided")]
+#[test_case("1e" => Ok(Update { consumed: 1, outcome: Next(()) }) ; "retain_exponent_marker")]
+#[test_case("1ex" => Ok(Update { consumed: 1, outcome: Parsed(1.0) }) ; "incomplete_exponent_unconsumed")]
+#[test_case("-2.5E-3," => Ok(Update { consumed: 7, outcome: Parsed(-0.0025) }) ; "exponent")]
+#[test_case(".5 " => Ok(Update { consumed: 2, outcome: Parsed(0.5) }) ; "lead_dot")]
+#[test_case("1. " => Ok(Update { consumed: 2, outcome: Parsed(1.0) }) ; "trail_dot")]
+#[test_case("." => Ok(Update { consumed: 1, outcome: Next(()) }) ; "dot_only")]
+#[test_case(".x" => Err(UnexpectedInput) ; "dot_mismatch")]
+#[test_case("x" => Err(UnexpectedInput) ; "mismatch")]
+fn float_f64(input: &str) -> ParseResult<Update<(), f64>, Infallible> {
+    float::<f64, _>().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("inf" => Ok(Some(f64::INFINITY)) ; "inf")]
+#[test_case("-Infinity" => Ok(Some(f64::NEG_INFINITY)) ; "infinity")]
+#[test_case("INFIN" => Ok(Some(f64::INFINITY)) ; "inf_prefix_of_infinity")]
+#[test_case("0.1" => Ok(Some(0.1)) ; "rounding")]
+#[test_case("9007199254740993" => Ok(Some(9007199254740992.0)) ; "ties_to_even")]
+#[test_case("1e400" => Ok(Some(f64::INFINITY)) ; "huge")]
+#[test_case("12e+" => Ok(Some(12.0)) ; "incomplete_exponent")]
+#[test_case("-" => Err(ExpectedMoreInput) ; "sign_only")]
+#[test_case("in" => Err(ExpectedMoreInput) ; "partial_word")]
+#[test_case("" => Err(ExpectedMoreInput) ; "empty")]
+fn float_f64_finalize(input: &str) -> ParseResult<Option<f64>, Infallible> {
+    float::<f64, _>().finalize(input)
+}
+
+#[test]
+fn nan() {
+    let parsed = float::<f32, _>()
+        .finalize(b"+NaN".as_slice())
+        .unwrap()
+        .unwrap();
+    assert!(parsed.is_nan());
+}
+
+#[test]
+fn retained_exponent_resolves_on_next_feed() {
+    let Update { consumed, outcome } = float::<f64, _>().feed("6.02e").unwrap();
+    assert_eq!(consumed, 4);
+
+    let Next(parser) = outcome else {
+        panic!("expected Next");
+    };
+    let Update { consumed, outcome } = parser.feed("e23 ").unwrap();
+    assert_eq!(consumed, 3);
+    assert!(matches!(outcome, Parsed(x) if x == 6.02e23));
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn float_then_unit(bufsize: usize) {
+    let (value, _) = float::<f64, _>()
+        .then(literal(b"em".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"1.25em".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(value, 1.25);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn utf8_float_then_text(bufsize: usize) {
+    let (value, _) = float::<f64, _>()
+        .then(literal("°"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("-40.5°".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(value, -40.5);
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct an [Identifier] parser which yields an identifier of a `XID_Start` or `_` character followed by `XID_Continue` characters
+pub fn identifier() -> Identifier {
+    Identifier::new(false)
+}
+
+/// Construct an [Identifier] parser which yields an identifier of an ASCII letter or `_` followed by ASCII letters, digits, or `_`
+pub fn ascii_identifier() -> Identifier {
+    Identifier::new(true)
+}
+
+/// Errors from parsing identifiers
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum IdentifierError {
+    /// The identifier is a reserved keyword
+    #[error("reserved keyword: {0:?}")]
+    Reserved(String),
+}
+
+/// An [Identifier] parser yields an owned identifier from `str` input
+///
+/// The end of an identifier is only decided when a non-identifier character is fed or upon [ParserCore::finalize]. An identifier in the [Identifier::reserved] set is rejected with [IdentifierError::Reserved].
+#[derive(Clone, Debug)]
+pub struct Identifier {
+    ascii: bool,
+    reserved: Rc<HashSet<String>>,
+    acc: String,
+}
+
+impl Identifier {
+    fn new(ascii: bool) -> Self {
+        Identifier {
+            ascii,
+            reserved: Rc::default(),
+            acc: String::new(),
+        }
+    }
+
+    /// Reject identifiers which exactly match any of `keywords`
+    pub fn reserved<I>(self, keywords: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Identifier {
+            reserved: Rc::new(keywords.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        match (self.ascii, self.acc.is_empty()) {
+            (true, true) => c.is_ascii_alphabetic() || c == '_',
+            (true, false) => c.is_ascii_alphanumeric() || c == '_',
+            (false, true) => unicode_ident::is_xid_start(c) || c == '_',
+            (false, false) => unicode_ident::is_xid_continue(c),
+        }
+    }
+
+    fn finish(self) -> ParseResult<String, IdentifierError> {
+        if self.reserved.contains(&self.acc) {
+            Err(Custom(IdentifierError::Reserved(self.acc)))
+        } else {
+            Ok(self.acc)
+        }
+    }
+}
+
+impl ParserCore<str> for Identifier {
+    type Output = String;
+    type Error = IdentifierError;
+
+    fn feed(mut self, buffer: &str) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        for (i, c) in buffer.char_indices() {
+            if self.accepts(c) {
+                self.acc.push(c);
+            } else if self.acc.is_empty() {
+                return Err(UnexpectedInput);
+            } else {
+                return Ok(Update {
+                    consumed: i,
+                    outcome: Parsed(self.finish()?),
+                });
+            }
+        }
+
+        Ok(Update {
+            consumed: buffer.len(),
+            outcome: Next(self),
+        })
+    }
+
+    fn finalize(self, buffer: &str) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(ident) => Ok(Some(ident)),
+            Next(ident) if ident.acc.is_empty() => Err(ExpectedMoreInput),
+            Next(ident) => ident.finish().map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
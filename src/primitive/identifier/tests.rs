@@ -0,0 +1,51 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::text::lexeme;
+use crate::primitive::{ascii_identifier, identifier, literal, IdentifierError};
+
+#[test_case("foo_1 = 2" => Ok(Update { consumed: 5, outcome: Parsed("foo_1".to_string()) }) ; "ascii")]
+#[test_case("_x+" => Ok(Update { consumed: 2, outcome: Parsed("_x".to_string()) }) ; "underscore_start")]
+#[test_case("größe;" => Ok(Update { consumed: 7, outcome: Parsed("größe".to_string()) }) ; "unicode")]
+#[test_case("変数 " => Ok(Update { consumed: 6, outcome: Parsed("変数".to_string()) }) ; "cjk")]
+#[test_case("foo" => Ok(Update { consumed: 3, outcome: Next(()) }) ; "undecided")]
+#[test_case("1x" => Err(UnexpectedInput) ; "digit_start")]
+#[test_case("🌏" => Err(UnexpectedInput) ; "emoji")]
+fn unicode(input: &str) -> ParseResult<Update<(), String>, IdentifierError> {
+    identifier().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("foo_1 " => Ok(Some("foo_1".to_string())) ; "ascii")]
+#[test_case("größe" => Ok(Some("gr".to_string())) ; "stops_at_non_ascii")]
+#[test_case("é" => Err(UnexpectedInput) ; "non_ascii_start")]
+#[test_case("" => Err(ExpectedMoreInput) ; "empty")]
+fn ascii(input: &str) -> ParseResult<Option<String>, IdentifierError> {
+    ascii_identifier().finalize(input)
+}
+
+#[test_case("if " => Err(Custom(IdentifierError::Reserved("if".to_string()))) ; "reserved")]
+#[test_case("if" => Err(Custom(IdentifierError::Reserved("if".to_string()))) ; "reserved_at_end")]
+#[test_case("iffy" => Ok(Some("iffy".to_string())) ; "reserved_prefix")]
+#[test_case("If" => Ok(Some("If".to_string())) ; "case_sensitive")]
+fn reserved(input: &str) -> ParseResult<Option<String>, IdentifierError> {
+    identifier().reserved(["if", "else"]).finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn assignment(bufsize: usize) {
+    let ((name, _), value) = lexeme(identifier())
+        .then(lexeme(literal("=")))
+        .then(identifier())
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("Größe = naïve".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!((name.as_str(), value.as_str()), ("Größe", "naïve"));
+}
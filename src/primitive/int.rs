@@ -0,0 +1,210 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct an [Int] parser which yields an unsigned decimal integer, such as `uint::<u32>()`
+pub fn uint<T, B>() -> Int<B, T>
+where
+    T: Integer,
+    B: ?Sized,
+{
+    Int::new(10, false)
+}
+
+/// Construct an [Int] parser which yields a decimal integer with an optional `+` or `-` sign, such as `int::<i64>()`
+pub fn int<T, B>() -> Int<B, T>
+where
+    T: Integer,
+    B: ?Sized,
+{
+    Int::new(10, true)
+}
+
+/// Construct an [Int] parser which yields an unsigned integer of hexadecimal digits in either case, without a `0x` prefix
+pub fn hex<T, B>() -> Int<B, T>
+where
+    T: Integer,
+    B: ?Sized,
+{
+    Int::new(16, false)
+}
+
+/// Construct an [Int] parser which yields an unsigned integer of octal digits, without a `0o` prefix
+pub fn octal<T, B>() -> Int<B, T>
+where
+    T: Integer,
+    B: ?Sized,
+{
+    Int::new(8, false)
+}
+
+/// Construct an [Int] parser which yields an unsigned integer of binary digits, without a `0b` prefix
+pub fn binary<T, B>() -> Int<B, T>
+where
+    T: Integer,
+    B: ?Sized,
+{
+    Int::new(2, false)
+}
+
+/// Errors from parsing numbers
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum NumberError {
+    /// The digits parsed so far do not fit in the output type
+    #[error("number overflows its type")]
+    Overflow,
+}
+
+/// Integer types which an [Int] parser may yield
+pub trait Integer: Copy + Default {
+    /// Append `digit` to `self` in `radix`, subtracting rather than adding when `negative`, or `None` on overflow
+    fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Integer for $t {
+                fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self> {
+                    let radix = <$t>::try_from(radix).ok()?;
+                    let digit = <$t>::try_from(digit).ok()?;
+                    let shifted = self.checked_mul(radix)?;
+                    if negative {
+                        shifted.checked_sub(digit)
+                    } else {
+                        shifted.checked_add(digit)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// An [Int] parser yields an integer of ASCII digits in a given radix from `str` or `[u8]` input
+///
+/// The integer ends at the first non-digit, so a complete integer is only yielded once a non-digit is fed or upon [ParserCore::finalize]. Negative values accumulate by subtraction, so `int::<i8>()` parses `"-128"`. Overflow is reported as [NumberError::Overflow] as soon as it occurs.
+pub struct Int<B, T>
+where
+    B: ?Sized,
+{
+    radix: u32,
+    accept_sign: bool,
+    negative: bool,
+    digits: usize,
+    value: T,
+    phantom: PhantomData<B>,
+}
+
+impl<B, T> Int<B, T>
+where
+    B: ?Sized,
+    T: Integer,
+{
+    fn new(radix: u32, signed: bool) -> Self {
+        Int {
+            radix,
+            accept_sign: signed,
+            negative: false,
+            digits: 0,
+            value: T::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B, T> Clone for Int<B, T>
+where
+    B: ?Sized,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Int {
+            radix: self.radix,
+            accept_sign: self.accept_sign,
+            negative: self.negative,
+            digits: self.digits,
+            value: self.value.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B, T> fmt::Debug for Int<B, T>
+where
+    B: ?Sized,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Int")
+            .field("radix", &self.radix)
+            .field("accept_sign", &self.accept_sign)
+            .field("negative", &self.negative)
+            .field("digits", &self.digits)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<B, T> ParserCore<B> for Int<B, T>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+    T: Integer,
+{
+    type Output = T;
+    type Error = NumberError;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let bytes = buffer.as_ref();
+        for (i, &byte) in bytes.iter().enumerate() {
+            if self.accept_sign && (byte == b'+' || byte == b'-') {
+                self.accept_sign = false;
+                self.negative = byte == b'-';
+                continue;
+            }
+            self.accept_sign = false;
+
+            match char::from(byte).to_digit(self.radix) {
+                Some(digit) => {
+                    self.value = self
+                        .value
+                        .push_digit(self.radix, digit, self.negative)
+                        .ok_or(Custom(NumberError::Overflow))?;
+                    self.digits += 1;
+                }
+                None if self.digits == 0 => return Err(UnexpectedInput),
+                None => {
+                    return Ok(Update {
+                        consumed: i,
+                        outcome: Parsed(self.value),
+                    })
+                }
+            }
+        }
+
+        Ok(Update {
+            consumed: bytes.len(),
+            outcome: Next(self),
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(value) => Ok(Some(value)),
+            Next(parser) if parser.digits > 0 => Ok(Some(parser.value)),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
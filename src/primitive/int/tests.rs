@@ -0,0 +1,75 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{binary, hex, int, literal, octal, uint, NumberError};
+
+#[test_case("123;" => Ok(Update { consumed: 3, outcome: Parsed(123) }) ; "terminated")]
+#[test_case("123" => Ok(Update { consumed: 3, outcome: Next(()) }) ; "undecided")]
+#[test_case("4294967295 " => Ok(Update { consumed: 10, outcome: Parsed(u32::MAX) }) ; "max")]
+#[test_case("4294967296" => Err(Custom(NumberError::Overflow)) ; "overflow")]
+#[test_case("+1" => Err(UnexpectedInput) ; "no_sign")]
+#[test_case("x" => Err(UnexpectedInput) ; "not_digit")]
+#[test_case("" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn uint_u32(input: &str) -> ParseResult<Update<(), u32>, NumberError> {
+    uint::<u32, _>().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(b"-128" => Ok(Some(-128)) ; "min")]
+#[test_case(b"+127" => Ok(Some(127)) ; "plus")]
+#[test_case(b"-129" => Err(Custom(NumberError::Overflow)) ; "underflow")]
+#[test_case(b"-" => Err(ExpectedMoreInput) ; "sign_only")]
+#[test_case(b"" => Err(ExpectedMoreInput) ; "empty")]
+#[test_case(b"--1" => Err(UnexpectedInput) ; "double_sign")]
+fn int_i8_finalize(input: &[u8]) -> ParseResult<Option<i8>, NumberError> {
+    int::<i8, _>().finalize(input)
+}
+
+#[test_case("ff" => Ok(Some(255)) ; "hex_lower")]
+#[test_case("FF" => Ok(Some(255)) ; "hex_upper")]
+#[test_case("100" => Err(Custom(NumberError::Overflow)) ; "hex_overflow")]
+fn hex_u8(input: &str) -> ParseResult<Option<u8>, NumberError> {
+    hex::<u8, _>().finalize(input)
+}
+
+#[test_case("0755" => Ok(Some(0o755)) ; "octal")]
+#[test_case("8" => Err(UnexpectedInput) ; "octal_not_digit")]
+fn octal_u16(input: &str) -> ParseResult<Option<u16>, NumberError> {
+    octal::<u16, _>().finalize(input)
+}
+
+#[test_case("1011" => Ok(Some(0b1011)) ; "binary")]
+#[test_case("12" => Ok(Some(1)) ; "binary_stops_at_non_digit")]
+fn binary_u64(input: &str) -> ParseResult<Option<u64>, NumberError> {
+    binary::<u64, _>().finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn signed_pair(bufsize: usize) {
+    let ((first, _), second) = int::<i64, _>()
+        .then(literal(b",".as_slice()))
+        .then(int::<i64, _>())
+        .parse_reader_with_bufsize::<_, Infallible>(b"-9223372036854775808,+7".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!((first, second), (i64::MIN, 7));
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn utf8_hex_then_text(bufsize: usize) {
+    let (value, _) = hex::<u32, _>()
+        .then(literal("🌏"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("beef🌏".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(value, 0xbeef);
+}
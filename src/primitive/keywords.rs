@@ -0,0 +1,173 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Keywords] parser which yields the index of the longest keyword matching the input
+///
+/// Keywords have the same type as the input, so a `str` parser only accepts `str` keywords and every match ends on a `char` boundary.
+pub fn keywords<B, I>(keywords: I) -> Keywords<B>
+where
+    B: ?Sized + AsRef<[u8]>,
+    I: IntoIterator,
+    I::Item: AsRef<B>,
+{
+    Keywords::from_iter(keywords)
+}
+
+/// A [Keywords] parser matches the longest of a set of keywords in a single pass, yielding its index
+///
+/// The keywords are compiled into a trie which is walked incrementally across feeds. Once some keyword has matched, input beyond it is left unconsumed until a longer keyword matches or becomes impossible, so the parser consumes exactly the longest match. When a keyword occurs more than once, the first index is yielded.
+///
+/// Errors are reported as by [Literal](crate::primitive::Literal): [UnexpectedInput] when no keyword can match, and [ExpectedMoreInput] when input ends partway through every candidate.
+pub struct Keywords<B>
+where
+    B: ?Sized,
+{
+    trie: Rc<[Node]>,
+    node: usize,
+    phantom: PhantomData<B>,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    edges: Vec<(u8, usize)>,
+    accept: Option<usize>,
+}
+
+impl<B> Clone for Keywords<B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Keywords {
+            trie: Rc::clone(&self.trie),
+            node: self.node,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B> fmt::Debug for Keywords<B>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keywords")
+            .field("nodes", &self.trie.len())
+            .field("node", &self.node)
+            .finish()
+    }
+}
+
+impl<B, K> FromIterator<K> for Keywords<B>
+where
+    B: ?Sized + AsRef<[u8]>,
+    K: AsRef<B>,
+{
+    fn from_iter<I: IntoIterator<Item = K>>(keywords: I) -> Self {
+        let mut trie = vec![Node::default()];
+
+        for (index, keyword) in keywords.into_iter().enumerate() {
+            let mut node = 0;
+            for &byte in keyword.as_ref().as_ref() {
+                node = match trie[node].edges.binary_search_by_key(&byte, |&(b, _)| b) {
+                    Ok(pos) => trie[node].edges[pos].1,
+                    Err(pos) => {
+                        let next = trie.len();
+                        trie[node].edges.insert(pos, (byte, next));
+                        trie.push(Node::default());
+                        next
+                    }
+                };
+            }
+            trie[node].accept.get_or_insert(index);
+        }
+
+        Keywords {
+            trie: trie.into(),
+            node: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B> Keywords<B>
+where
+    B: ?Sized,
+{
+    fn step(&self, node: usize, byte: u8) -> Option<usize> {
+        let edges = &self.trie[node].edges;
+        edges
+            .binary_search_by_key(&byte, |&(b, _)| b)
+            .ok()
+            .map(|pos| edges[pos].1)
+    }
+
+    /// Walk `bytes` from the current node, returning the node reached if a longer match remains possible, along with the length and node of the last accepting point
+    fn scan(&self, bytes: &[u8]) -> (Option<usize>, Option<(usize, usize)>) {
+        let mut node = self.node;
+        let mut last = None;
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            let Some(next) = self.step(node, byte) else {
+                return (None, last);
+            };
+            node = next;
+            if self.trie[node].accept.is_some() {
+                last = Some((i + 1, node));
+            }
+        }
+
+        let alive = !self.trie[node].edges.is_empty();
+        (alive.then_some(node), last)
+    }
+}
+
+impl<B> ParserCore<B> for Keywords<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    type Output = usize;
+    type Error = Infallible;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let bytes = buffer.as_ref();
+        let pending = self.trie[self.node].accept;
+
+        let (consumed, outcome) = match self.scan(bytes) {
+            (None, Some((len, node))) => (len, Parsed(self.trie[node].accept.unwrap())),
+            (None, None) => (0, Parsed(pending.ok_or(UnexpectedInput)?)),
+            // Retain input beyond the last match in case a longer keyword matches:
+            (Some(_), Some((len, node))) => (len, Next(Keywords { node, ..self })),
+            (Some(_), None) if pending.is_some() => (0, Next(self)),
+            (Some(node), None) => (bytes.len(), Next(Keywords { node, ..self })),
+        };
+
+        Ok(Update { consumed, outcome })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        match self.scan(buffer.as_ref()) {
+            (_, Some((_, node))) => Ok(self.trie[node].accept),
+            (alive, None) => self.trie[self.node]
+                .accept
+                .map(Some)
+                .ok_or(if alive.is_some() {
+                    ExpectedMoreInput
+                } else {
+                    UnexpectedInput
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,104 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{keywords, literal};
+use crate::sequence::SequenceParser;
+
+const SQL: [&str; 5] = ["in", "insert", "int", "into", "select"];
+
+#[test_case("select *" => matches Ok(Update { consumed: 6, outcome: Parsed(4) }) ; "unique")]
+#[test_case("int " => matches Ok(Update { consumed: 3, outcome: Parsed(2) }) ; "longest_of_shared_prefix")]
+#[test_case("in (" => matches Ok(Update { consumed: 2, outcome: Parsed(0) }) ; "shortest")]
+#[test_case("inse" => matches Ok(Update { consumed: 2, outcome: Next(()) }) ; "retain_after_match")]
+#[test_case("se" => matches Ok(Update { consumed: 2, outcome: Next(()) }) ; "partial_before_match")]
+#[test_case("" => matches Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+#[test_case("update" => matches Err(UnexpectedInput) ; "mismatch")]
+#[test_case("sel;" => matches Err(UnexpectedInput) ; "mismatch_partway")]
+fn sql_feed(input: &str) -> ParseResult<Update<(), usize>, Infallible> {
+    keywords(SQL).feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("into" => Ok(Some(3)) ; "exact")]
+#[test_case("inser" => Ok(Some(0)) ; "falls_back_to_shorter")]
+#[test_case("sel" => Err(ExpectedMoreInput) ; "partial")]
+#[test_case("x" => Err(UnexpectedInput) ; "mismatch")]
+fn sql_finalize(input: &str) -> ParseResult<Option<usize>, Infallible> {
+    keywords(SQL).finalize(input)
+}
+
+#[test]
+fn retained_match_resolves_on_next_feed() {
+    let Update { consumed, outcome } = keywords(SQL).feed("ins").unwrap();
+    assert_eq!(consumed, 2);
+
+    let Next(parser) = outcome else {
+        panic!("expected Next");
+    };
+    let Update { consumed, outcome } = parser.feed("s!").unwrap();
+    assert_eq!(consumed, 0);
+    assert!(matches!(outcome, Parsed(0)));
+}
+
+#[test]
+fn duplicate_keyword_yields_first_index() {
+    let parsed = keywords(["a", "b", "a"]).finalize("a".as_bytes());
+    assert_eq!(parsed, Ok(Some(0)));
+}
+
+#[test]
+fn owned_str_keywords() {
+    let parsed = keywords(vec![String::from("é"), String::from("ê")]).feed("êtes");
+    assert!(matches!(
+        parsed,
+        Ok(Update {
+            consumed: 2,
+            outcome: Parsed(1)
+        })
+    ));
+}
+
+#[test_case(b"\xC3\xA9" => matches Ok(Update { consumed: 1, outcome: Parsed(0) }) ; "partial_char")]
+#[test_case(b"\xC3" => matches Ok(Update { consumed: 1, outcome: Next(()) }) ; "retain_after_match")]
+fn non_utf8_bytes(input: &[u8]) -> ParseResult<Update<(), usize>, Infallible> {
+    keywords([b"\xC3".as_slice(), b"\xC3\xA8"])
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test]
+fn empty_set_rejects() {
+    let parsed = keywords(Vec::<&[u8]>::new()).feed(b"".as_slice());
+    assert!(matches!(parsed, Err(UnexpectedInput)));
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn repeated_longest(bufsize: usize) {
+    let indices = keywords(SQL)
+        .repeated()
+        .collect::<Vec<_>>()
+        .parse_reader_with_bufsize::<_, Infallible>(b"intoinsertinintselect".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(indices, vec![3, 1, 0, 2, 4]);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn utf8_keywords(bufsize: usize) {
+    let (ix, _) = keywords(["🌏", "🌏🌎", "é"])
+        .then(literal("!"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("🌏🌎!".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(ix, 1);
+}
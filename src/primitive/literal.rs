@@ -2,11 +2,14 @@ use std::convert::Infallible;
 
 use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
 use crate::error::ParseResult;
-use crate::parser::Update;
+use crate::parser::{Context, Update};
 use crate::{buffer::BufRef, parser::ParserCore};
 
 /// Construct a [Literal] which parses input which exactly matches its value
-pub fn literal<B>(value: &B) -> Literal<'_, B> {
+pub fn literal<B>(value: &B) -> Literal<'_, B>
+where
+    B: ?Sized,
+{
     Literal::from(value)
 }
 
@@ -16,8 +19,8 @@ pub struct Literal<'s, B>
 where
     B: ?Sized,
 {
-    value: &'s B,
-    matchcnt: usize,
+    pub(super) value: &'s B,
+    pub(super) matchcnt: usize,
 }
 
 impl<B> Clone for Literal<'_, B>
@@ -83,8 +86,24 @@ where
     }
 
     fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
-        assert!(buffer.is_empty());
-        Err(ExpectedMoreInput)
+        Ok(self
+            .finalize_consumed(Context::default(), buffer)?
+            .map(|(_, value)| value))
+    }
+
+    fn finalize_consumed(
+        self,
+        _: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        // The buffer may hold input not yet fed, such as when an alternative is finalized:
+        let Update { consumed, outcome } = self.feed(buffer)?;
+        match outcome {
+            Parsed(value) => Ok(Some((consumed, value))),
+            Next(_) => Err(ExpectedMoreInput),
+        }
     }
 }
 
@@ -1,13 +1,14 @@
 use std::convert::Infallible;
 
+use either::Either;
 use test_case::test_case;
 
 use crate::buffer::BufRef;
-use crate::error::ParseError::UnexpectedInput;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
 use crate::error::ParseResult;
 use crate::parser::Outcome::{Next, Parsed};
-use crate::parser::{ParserCore, Update};
-use crate::primitive::Literal;
+use crate::parser::{Context, ParserCore, PushParser, Update};
+use crate::primitive::{literal, Literal};
 
 #[test_case(
     "Hello",
@@ -42,3 +43,36 @@ where
 {
     Literal::from(literal).feed(input)
 }
+
+#[test_case("Hello", "" => Err(ExpectedMoreInput) ; "empty")]
+#[test_case("Hello", "Hell" => Err(ExpectedMoreInput) ; "prefix")]
+#[test_case("Hello", "Hello" => Ok(Some("Hello")) ; "exact")]
+#[test_case("Hello", "Help" => Err(UnexpectedInput) ; "mismatch")]
+fn finalize_literal<'a>(
+    literal: &'a str,
+    input: &'a str,
+) -> ParseResult<Option<&'a str>, Infallible> {
+    Literal::from(literal).finalize(input)
+}
+
+#[test_case("ab" => Ok(Some(Either::Right("ab"))) ; "shorter_alternative")]
+#[test_case("abc" => Ok(Some(Either::Left("abc"))) ; "longer_alternative")]
+#[test_case("a" => Err(ExpectedMoreInput) ; "prefix")]
+fn finalize_or(input: &str) -> ParseResult<Option<Either<&str, &str>>, Infallible> {
+    let Update { consumed, outcome } = literal("abc").or(literal("ab")).feed(input)?;
+    match outcome {
+        // The backtracking alternatives retain the input, which is passed to `finalize`:
+        Next(p) => p.finalize(&input[consumed..]),
+        Parsed(output) => Ok(Some(output)),
+    }
+}
+
+#[test_case("ab", "ab;" => Ok(Some((2, "ab"))) ; "prefix")]
+#[test_case("ab", "ab" => Ok(Some((2, "ab"))) ; "exact")]
+#[test_case("ab", "a" => Err(ExpectedMoreInput) ; "short")]
+fn finalize_consumed<'a>(
+    lit: &'a str,
+    input: &str,
+) -> ParseResult<Option<(usize, &'a str)>, Infallible> {
+    literal(lit).finalize_consumed(Context::default(), input)
+}
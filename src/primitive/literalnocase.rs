@@ -0,0 +1,162 @@
+use std::convert::Infallible;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [LiteralNoCase] which parses input which matches its value ignoring ASCII case
+pub fn literal_no_case<B>(value: &B) -> LiteralNoCase<'_, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: CaseFold,
+{
+    LiteralNoCase {
+        value,
+        matchcnt: 0,
+        unicode: false,
+    }
+}
+
+/// Construct a [LiteralNoCase] which parses input which matches its value under Unicode simple case folding
+///
+/// Simple case folding only maps characters one-to-one, so for example `"STRASSE"` does not match `"straße"`, while the Kelvin sign `"\u{212A}"` matches `"k"` and the dotless `"ı"` matches neither `"i"` nor `"I"`.
+pub fn literal_no_case_unicode(value: &str) -> LiteralNoCase<'_, str> {
+    LiteralNoCase {
+        value,
+        matchcnt: 0,
+        unicode: true,
+    }
+}
+
+/// A [LiteralNoCase] parses input which matches its value case-insensitively, yielding the value
+///
+/// Matched input may differ in length from the value, such as for `"\u{212A}"` and `"k"`, so `matchcnt` tracks progress through the value rather than through input.
+#[derive(Copy, Debug)]
+pub struct LiteralNoCase<'s, B>
+where
+    B: ?Sized,
+{
+    value: &'s B,
+    matchcnt: usize,
+    unicode: bool,
+}
+
+impl<B> Clone for LiteralNoCase<'_, B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        LiteralNoCase {
+            value: self.value,
+            matchcnt: self.matchcnt,
+            unicode: self.unicode,
+        }
+    }
+}
+
+impl<'s, B> ParserCore<B> for LiteralNoCase<'s, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: CaseFold,
+{
+    type Output = &'s B;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let mut consumed = 0;
+        loop {
+            let Some((litc, litlen)) = self.value.drop_up_to(self.matchcnt).first_item() else {
+                // We've reached the end of a match:
+                return Ok(Update {
+                    consumed,
+                    outcome: Parsed(self.value),
+                });
+            };
+
+            let Some((bufc, buflen)) = buffer.drop_up_to(consumed).first_item() else {
+                return Ok(Update {
+                    consumed,
+                    outcome: Next(self),
+                });
+            };
+
+            if litc.eq_fold(&bufc, self.unicode) {
+                self.matchcnt += litlen;
+                consumed += buflen;
+            } else {
+                return Err(UnexpectedInput);
+            }
+        }
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(self
+            .finalize_consumed(Context::default(), buffer)?
+            .map(|(_, value)| value))
+    }
+
+    fn finalize_consumed(
+        self,
+        _: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let Update { consumed, outcome } = self.feed(buffer)?;
+        match outcome {
+            Parsed(value) => Ok(Some((consumed, value))),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+/// Items which may be compared case-insensitively
+pub trait CaseFold {
+    /// Compare ignoring ASCII case, or under Unicode simple case folding when `unicode` is set and the item is a `char`
+    fn eq_fold(&self, other: &Self, unicode: bool) -> bool;
+}
+
+impl CaseFold for u8 {
+    fn eq_fold(&self, other: &Self, _: bool) -> bool {
+        self.eq_ignore_ascii_case(other)
+    }
+}
+
+impl CaseFold for char {
+    fn eq_fold(&self, other: &Self, unicode: bool) -> bool {
+        if unicode {
+            simple_fold(*self) == simple_fold(*other)
+        } else {
+            self.eq_ignore_ascii_case(other)
+        }
+    }
+}
+
+/// Fold `c` under Unicode simple case folding (`CaseFolding.txt` statuses C and S)
+///
+/// Mapping through single-character uppercase then lowercase agrees with the folding table, except for the dotless `'ı'`, which has no folding, and the characters listed below whose uppercase is more than one character.
+fn simple_fold(c: char) -> char {
+    fn single(mut it: impl Iterator<Item = char>, c: char) -> char {
+        match (it.next(), it.next()) {
+            (Some(mapped), None) => mapped,
+            _ => c,
+        }
+    }
+
+    match c {
+        '\u{0131}' => c,
+        '\u{1FD3}' => '\u{0390}',
+        '\u{1FE3}' => '\u{03B0}',
+        '\u{FB05}' => '\u{FB06}',
+        _ => {
+            let upper = single(c.to_uppercase(), c);
+            single(upper.to_lowercase(), upper)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
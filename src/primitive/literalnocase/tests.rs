@@ -0,0 +1,79 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, Context, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal_no_case, literal_no_case_unicode};
+
+#[test_case("Content-Length", "content-length: 5" => Ok(Update { consumed: 14, outcome: Parsed(()) }) ; "lower")]
+#[test_case("select", "SELECT *" => Ok(Update { consumed: 6, outcome: Parsed(()) }) ; "upper")]
+#[test_case("select", "SEL" => Ok(Update { consumed: 3, outcome: Next(3) }) ; "partial")]
+#[test_case("select", "SELF" => Err(UnexpectedInput) ; "mismatch")]
+#[test_case("straße", "STRASSE" => Err(UnexpectedInput) ; "no_unicode_folding")]
+fn ascii_str(lit: &str, input: &str) -> ParseResult<Update<usize, ()>, Infallible> {
+    literal_no_case(lit)
+        .feed(input)
+        .map(|up| up.map_next(|p| p.matchcnt).map_output(|_| ()))
+}
+
+#[test_case("straße", "STRAẞE!" => Ok(Update { consumed: 8, outcome: Parsed(()) }) ; "capital_sharp_s")]
+#[test_case("k", "\u{212A}" => Ok(Update { consumed: 3, outcome: Parsed(()) }) ; "kelvin_sign")]
+#[test_case("σοφός", "ΣΟΦΌΣ" => Ok(Update { consumed: 10, outcome: Parsed(()) }) ; "final_sigma")]
+#[test_case("\u{212A}elvin", "k" => Ok(Update { consumed: 1, outcome: Next(3) }) ; "partial_kelvin_counts_literal_units")]
+#[test_case("straße", "STRASSE" => Err(UnexpectedInput) ; "no_full_folding")]
+#[test_case("i", "ı" => Err(UnexpectedInput) ; "dotless_i")]
+#[test_case("I", "ı" => Err(UnexpectedInput) ; "dotless_i_upper")]
+#[test_case("ı", "ı" => Ok(Update { consumed: 2, outcome: Parsed(()) }) ; "dotless_i_exact")]
+#[test_case("i", "İ" => Err(UnexpectedInput) ; "dotted_capital_i")]
+#[test_case("İ", "i" => Err(UnexpectedInput) ; "dotted_capital_i_literal")]
+#[test_case("\u{0390}", "\u{1FD3}" => Ok(Update { consumed: 3, outcome: Parsed(()) }) ; "status_s_iota")]
+#[test_case("\u{FB06}", "\u{FB05}" => Ok(Update { consumed: 3, outcome: Parsed(()) }) ; "status_s_ligature")]
+fn unicode_str(lit: &str, input: &str) -> ParseResult<Update<usize, ()>, Infallible> {
+    literal_no_case_unicode(lit)
+        .feed(input)
+        .map(|up| up.map_next(|p| p.matchcnt).map_output(|_| ()))
+}
+
+#[test_case("select", "SELECT *" => Ok(Some(6)) ; "prefix")]
+#[test_case("select", "SEL" => Err(ExpectedMoreInput) ; "short")]
+fn ascii_str_finalize_consumed(lit: &str, input: &str) -> ParseResult<Option<usize>, Infallible> {
+    literal_no_case(lit)
+        .finalize_consumed(Context::default(), input)
+        .map(|optval| optval.map(|(consumed, _)| consumed))
+}
+
+#[test_case(b"GET", b"get" => Ok(Some(())) ; "exact")]
+#[test_case(b"GET", b"ge" => Err(ExpectedMoreInput) ; "short")]
+fn ascii_bytes_finalize(lit: &[u8], input: &[u8]) -> ParseResult<Option<()>, Infallible> {
+    literal_no_case(lit)
+        .finalize(input)
+        .map(|optval| optval.map(|_| ()))
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn ascii_bytes_reader(bufsize: usize) {
+    let (method, _) = literal_no_case(b"GET".as_slice())
+        .then(literal_no_case(b" /".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"gEt /".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(method, b"GET");
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn unicode_utf8_reader(bufsize: usize) {
+    let (word, _) = literal_no_case_unicode("größe")
+        .then(literal_no_case_unicode("!"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("GRÖẞE!".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(word, "größe");
+}
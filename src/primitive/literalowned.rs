@@ -0,0 +1,120 @@
+use std::borrow::Borrow;
+use std::convert::Infallible;
+use std::fmt;
+
+use crate::buffer::BufRef;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+use crate::primitive::Literal;
+
+/// Construct a [LiteralOwned] which parses input which exactly matches its owned value
+pub fn literal_owned<B, V>(value: V) -> LiteralOwned<B>
+where
+    B: ?Sized + ToOwned,
+    V: Into<B::Owned>,
+{
+    LiteralOwned::new(value.into())
+}
+
+/// A [LiteralOwned] parses input which exactly matches its owned value, yielding that value
+///
+/// This is useful where the value is not known statically, such as in grammars built at runtime. It otherwise behaves as a [Literal].
+pub struct LiteralOwned<B>
+where
+    B: ?Sized + ToOwned,
+{
+    value: B::Owned,
+    matchcnt: usize,
+}
+
+impl<B> LiteralOwned<B>
+where
+    B: ?Sized + ToOwned,
+{
+    /// Construct a new `LiteralOwned`
+    pub fn new(value: B::Owned) -> Self {
+        LiteralOwned { value, matchcnt: 0 }
+    }
+}
+
+impl<B> Clone for LiteralOwned<B>
+where
+    B: ?Sized + ToOwned,
+    B::Owned: Clone,
+{
+    fn clone(&self) -> Self {
+        LiteralOwned {
+            value: self.value.clone(),
+            matchcnt: self.matchcnt,
+        }
+    }
+}
+
+impl<B> fmt::Debug for LiteralOwned<B>
+where
+    B: ?Sized + ToOwned,
+    B::Owned: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LiteralOwned")
+            .field("value", &self.value)
+            .field("matchcnt", &self.matchcnt)
+            .finish()
+    }
+}
+
+impl<B> ParserCore<B> for LiteralOwned<B>
+where
+    B: ?Sized + BufRef + ToOwned,
+{
+    type Output = B::Owned;
+    type Error = Infallible;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let LiteralOwned { value, matchcnt } = self;
+
+        let Update { consumed, outcome } = Literal {
+            value: value.borrow(),
+            matchcnt,
+        }
+        .feed(buffer)?
+        .map_next(|lit| lit.matchcnt)
+        .map_output(|_| ());
+
+        Ok(Update {
+            consumed,
+            outcome: match outcome {
+                Next(matchcnt) => Next(LiteralOwned { value, matchcnt }),
+                Parsed(()) => Parsed(value),
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(self
+            .finalize_consumed(Context::default(), buffer)?
+            .map(|(_, value)| value))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        let LiteralOwned { value, matchcnt } = self;
+
+        let consumed = Literal {
+            value: value.borrow(),
+            matchcnt,
+        }
+        .finalize_consumed(ctx, buffer)?
+        .map(|(consumed, _)| consumed);
+
+        Ok(consumed.map(|consumed| (consumed, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,74 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, Context, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal_owned, LiteralOwned};
+
+#[test_case("Hello", "Hello World!" => Ok(Update { consumed: 5, outcome: Parsed("Hello".to_string()) }) ; "prefix")]
+#[test_case("Hello", "Hell" => Ok(Update { consumed: 4, outcome: Next(4) }) ; "partial")]
+#[test_case("Hello", "Goodbye" => Err(UnexpectedInput) ; "mismatch")]
+fn owned_str(lit: &str, input: &str) -> ParseResult<Update<usize, String>, Infallible> {
+    literal_owned::<str, _>(lit)
+        .feed(input)
+        .map(|up| up.map_next(|p| p.matchcnt))
+}
+
+#[test_case("Hello", "Hello" => Ok(Some("Hello".to_string())) ; "exact")]
+#[test_case("Hello", "Hel" => Err(ExpectedMoreInput) ; "prefix")]
+#[test_case("Hello", "" => Err(ExpectedMoreInput) ; "empty")]
+#[test_case("Hello", "Help" => Err(UnexpectedInput) ; "mismatch")]
+fn owned_str_finalize(lit: &str, input: &str) -> ParseResult<Option<String>, Infallible> {
+    literal_owned::<str, _>(lit).finalize(input)
+}
+
+#[test_case("Hello", "Hello World!" => Ok(Some((5, "Hello".to_string()))) ; "prefix")]
+#[test_case("Hello", "Hel" => Err(ExpectedMoreInput) ; "short")]
+fn owned_str_finalize_consumed(
+    lit: &str,
+    input: &str,
+) -> ParseResult<Option<(usize, String)>, Infallible> {
+    literal_owned::<str, _>(lit).finalize_consumed(Context::default(), input)
+}
+
+#[test]
+fn owned_str_across_feeds() {
+    let Update { consumed, outcome } = literal_owned::<str, _>("Hello").feed("He").unwrap();
+    assert_eq!(consumed, 2);
+
+    let Next(parser) = outcome else {
+        panic!("expected Next");
+    };
+    let Update { consumed, outcome } = parser.feed("llo!").unwrap();
+    assert_eq!(consumed, 3);
+    assert!(matches!(outcome, Parsed(s) if s == "Hello"));
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn owned_bytes_reader(bufsize: usize) {
+    let name = String::from("key");
+    let (key, _) = LiteralOwned::<[u8]>::new(name.into_bytes())
+        .then(literal_owned::<[u8], _>(b"=".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"key=".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(key, b"key");
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn owned_utf8_reader(bufsize: usize) {
+    let (word, _) = literal_owned::<str, _>(String::from("🌏"))
+        .then(literal_owned::<str, _>("!"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("🌏!".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(word, "🌏");
+}
@@ -0,0 +1,67 @@
+use std::convert::Infallible;
+
+use crate::buffer::BufRef;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [Position] parser which yields the current input offset
+pub fn position() -> Position {
+    Position
+}
+
+/// A [Position] parser yields the absolute input offset without consuming input
+///
+/// The offset is absolute when driven by [ParserCore::feed_with], as [BufferManager](crate::buffer::BufferManager) does, through combinators which forward the [Context]. When driven directly by [ParserCore::feed], it yields 0.
+#[derive(Copy, Clone, Debug)]
+pub struct Position;
+
+impl<B> ParserCore<B> for Position
+where
+    B: ?Sized,
+{
+    type Output = usize;
+    type Error = Infallible;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        Ok(Update {
+            consumed: 0,
+            outcome: Parsed(ctx.offset()),
+        })
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(Some(ctx.offset()))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        Ok(Some((0, ctx.offset())))
+    }
+}
+
+#[cfg(test)]
+mod tests;
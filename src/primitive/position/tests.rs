@@ -0,0 +1,40 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser};
+use crate::primitive::{literal, position, take_while};
+
+#[test]
+fn direct_feed_is_zero() {
+    assert_eq!(ParserCore::<str>::finalize(position(), "abc"), Ok(Some(0)));
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn offsets(bufsize: usize) {
+    let ((((_, before), _), after), _) = literal(b"ab".as_slice())
+        .then(position())
+        .then(take_while(|b: u8| b == b' '))
+        .then(position())
+        .then(literal(b"c".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(b"ab   c".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!((before, after), (2, 5));
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn utf8_offsets_count_bytes(bufsize: usize) {
+    let ((_, pos), _) = literal("🌏é")
+        .then(position())
+        .then(literal("!"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("🌏é!".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(pos, 6);
+}
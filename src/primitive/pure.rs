@@ -0,0 +1,51 @@
+use std::convert::Infallible;
+
+use crate::buffer::BufRef;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [Pure] parser which yields `value` without consuming input
+pub fn pure<T>(value: T) -> Pure<T> {
+    Pure(value)
+}
+
+/// A [Pure] parser always succeeds without consuming input, yielding its value
+///
+/// This is useful as a fallback alternative of [Or](crate::combinator::Or), or as the seed of a sequence built at runtime.
+#[derive(Clone, Debug)]
+pub struct Pure<T>(T);
+
+impl<B, T> ParserCore<B> for Pure<T>
+where
+    B: ?Sized,
+{
+    type Output = T;
+    type Error = Infallible;
+
+    fn feed(self, _: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        Ok(Update {
+            consumed: 0,
+            outcome: Parsed(self.0),
+        })
+    }
+
+    fn finalize(self, _: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(Some(self.0))
+    }
+
+    fn finalize_consumed(
+        self,
+        _: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        Ok(Some((0, self.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,63 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use either::Either::{self, Left, Right};
+
+use crate::error::ParseResult;
+use crate::parser::Outcome::Parsed;
+use crate::parser::{ByteParser, ParserCore, PushParser, Update};
+use crate::primitive::{literal, pure, uint};
+
+type Fallback<'a> = (Either<&'a str, &'a str>, &'a str);
+
+#[test_case("" ; "empty")]
+#[test_case("abc" ; "nonempty")]
+fn pure_consumes_nothing(input: &str) {
+    let parsed: ParseResult<_, Infallible> = pure(7).feed(input);
+    assert!(matches!(
+        parsed,
+        Ok(Update {
+            consumed: 0,
+            outcome: Parsed(7)
+        })
+    ));
+    assert_eq!(ParserCore::<str>::finalize(pure(7), ""), Ok(Some(7)));
+}
+
+#[test_case(b"port=8080".as_slice() => 8080 ; "given")]
+#[test_case(b"port=".as_slice() => 80 ; "default")]
+fn or_default(input: &[u8]) -> u32 {
+    let (_, port) = literal(b"port=".as_slice())
+        .then(uint::<u32, _>().or(pure(80)))
+        .parse_reader_with_bufsize::<_, Infallible>(input, 1)
+        .unwrap();
+
+    port.into_inner()
+}
+
+#[test]
+fn then_seed() {
+    let Update { consumed, outcome } = pure(vec![1u8]).then(literal("x")).feed("xy").unwrap();
+
+    assert_eq!(consumed, 1);
+    assert!(matches!(outcome, Parsed((seed, "x")) if seed == vec![1]));
+}
+
+#[test_case("ab" => Ok(Some((Right("x"), "ab"))) ; "fallback")]
+#[test_case("abcab" => Ok(Some((Left("abc"), "ab"))) ; "first")]
+fn or_fallback_then(
+    input: &str,
+) -> ParseResult<Option<Fallback<'_>>, Either<Infallible, Infallible>> {
+    literal("abc")
+        .or(pure("x"))
+        .then(literal("ab"))
+        .finalize(input)
+}
+
+#[test]
+fn or_fallback_span() {
+    let spanned = literal("abc").or(pure("x")).with_span().finalize("ab");
+
+    assert_eq!(spanned, Ok(Some((0..0, Right("x")))));
+}
@@ -0,0 +1,242 @@
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [QuotedString] parser which yields the decoded contents of a quoted string
+pub fn quoted_string(config: QuoteConfig) -> QuotedString {
+    QuotedString {
+        config,
+        state: State::Open,
+        acc: String::new(),
+    }
+}
+
+/// The syntax of a quoted string
+///
+/// The [Default] is a JSON string: double quotes with the backslash escapes of [QuoteConfig::JSON_ESCAPES], including `\uXXXX` escapes with UTF-16 surrogate pairs.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QuoteConfig {
+    /// The quote character which opens and closes the string
+    pub quote: char,
+    /// The character which begins an escape sequence, if any
+    pub escape: Option<char>,
+    /// The characters which may follow the escape character, each with the character it decodes to
+    ///
+    /// The quote and escape characters themselves may always be escaped.
+    pub escapes: &'static [(char, char)],
+    /// Whether a doubled quote inside the string denotes a single quote, as in CSV
+    pub doubled_quote: bool,
+    /// Whether `\uXXXX` escapes are decoded
+    pub unicode_escapes: bool,
+}
+
+impl Default for QuoteConfig {
+    fn default() -> Self {
+        QuoteConfig {
+            quote: '"',
+            escape: Some('\\'),
+            escapes: QuoteConfig::JSON_ESCAPES,
+            doubled_quote: false,
+            unicode_escapes: true,
+        }
+    }
+}
+
+impl QuoteConfig {
+    /// The escapes of a JSON string besides `\"`, `\\` and `\uXXXX`
+    pub const JSON_ESCAPES: &'static [(char, char)] = &[
+        ('/', '/'),
+        ('b', '\u{8}'),
+        ('f', '\u{c}'),
+        ('n', '\n'),
+        ('r', '\r'),
+        ('t', '\t'),
+    ];
+
+    /// A CSV field: double quotes without escapes, where `""` denotes `"`
+    pub fn csv() -> Self {
+        QuoteConfig {
+            quote: '"',
+            escape: None,
+            escapes: &[],
+            doubled_quote: true,
+            unicode_escapes: false,
+        }
+    }
+}
+
+/// Errors from decoding quoted strings
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum QuoteError {
+    /// An escape character was followed by an unsupported character
+    #[error("invalid escape sequence: {0:?}")]
+    InvalidEscape(char),
+
+    /// A unicode escape contained a non-hexadecimal digit
+    #[error("invalid hex digit in unicode escape: {0:?}")]
+    InvalidHex(char),
+
+    /// A unicode escape encoded half of a UTF-16 surrogate pair without the other half
+    #[error("unpaired surrogate in unicode escape")]
+    LoneSurrogate,
+}
+
+/// A [QuotedString] parser decodes a quoted string from `str` input into an owned `String`
+///
+/// The escapes of [QuoteConfig::escapes], an escaped quote, and an escaped escape character are decoded, along with `\uXXXX` if enabled by [QuoteConfig::unicode_escapes]; any other escape is a [QuoteError::InvalidEscape]. Escape sequences may be split across buffers.
+///
+/// With [QuoteConfig::doubled_quote], a closing quote is only decided once the following character is fed or upon [ParserCore::finalize].
+#[derive(Clone, Debug)]
+pub struct QuotedString {
+    config: QuoteConfig,
+    state: State,
+    acc: String,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum State {
+    Open,
+    Body,
+    QuoteSeen,
+    Escape,
+    Hex {
+        value: u32,
+        digits: u8,
+        high: Option<u32>,
+    },
+    PairEscape {
+        high: u32,
+    },
+    PairU {
+        high: u32,
+    },
+}
+
+impl QuotedString {
+    /// Decode the character after an escape character
+    fn escape(&mut self, c: char) -> Result<State, QuoteError> {
+        let decoded = match c {
+            'u' if self.config.unicode_escapes => {
+                return Ok(State::Hex {
+                    value: 0,
+                    digits: 0,
+                    high: None,
+                })
+            }
+            c if c == self.config.quote || Some(c) == self.config.escape => c,
+            c => match self.config.escapes.iter().find(|&&(e, _)| e == c) {
+                Some(&(_, decoded)) => decoded,
+                None => return Err(QuoteError::InvalidEscape(c)),
+            },
+        };
+        self.acc.push(decoded);
+        Ok(State::Body)
+    }
+
+    /// Accumulate a hex digit of a `\uXXXX` escape
+    fn hex(
+        &mut self,
+        c: char,
+        value: u32,
+        digits: u8,
+        high: Option<u32>,
+    ) -> Result<State, QuoteError> {
+        let digit = c.to_digit(16).ok_or(QuoteError::InvalidHex(c))?;
+        let value = value << 4 | digit;
+        if digits < 3 {
+            return Ok(State::Hex {
+                value,
+                digits: digits + 1,
+                high,
+            });
+        }
+
+        let code = match (high, value) {
+            (None, 0xd800..=0xdbff) => return Ok(State::PairEscape { high: value }),
+            (Some(high), 0xdc00..=0xdfff) => 0x10000 + ((high - 0xd800) << 10 | (value - 0xdc00)),
+            (None, _) => value,
+            (Some(_), _) => return Err(QuoteError::LoneSurrogate),
+        };
+        self.acc
+            .push(char::from_u32(code).ok_or(QuoteError::LoneSurrogate)?);
+        Ok(State::Body)
+    }
+}
+
+impl ParserCore<str> for QuotedString {
+    type Output = String;
+    type Error = QuoteError;
+
+    fn feed(mut self, buffer: &str) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let QuoteConfig {
+            quote,
+            escape,
+            doubled_quote,
+            ..
+        } = self.config;
+
+        for (i, c) in buffer.char_indices() {
+            self.state = match self.state {
+                State::Open if c == quote => State::Body,
+                State::Open => return Err(UnexpectedInput),
+                State::Body if c == quote && doubled_quote => State::QuoteSeen,
+                State::Body if c == quote => {
+                    return Ok(Update {
+                        consumed: i + c.len_utf8(),
+                        outcome: Parsed(self.acc),
+                    })
+                }
+                State::Body if Some(c) == escape => State::Escape,
+                State::Body => {
+                    self.acc.push(c);
+                    State::Body
+                }
+                State::QuoteSeen if c == quote => {
+                    self.acc.push(c);
+                    State::Body
+                }
+                State::QuoteSeen => {
+                    return Ok(Update {
+                        consumed: i,
+                        outcome: Parsed(self.acc),
+                    })
+                }
+                State::Escape => self.escape(c).map_err(Custom)?,
+                State::Hex {
+                    value,
+                    digits,
+                    high,
+                } => self.hex(c, value, digits, high).map_err(Custom)?,
+                State::PairEscape { high } if Some(c) == escape => State::PairU { high },
+                State::PairU { high } if c == 'u' => State::Hex {
+                    value: 0,
+                    digits: 0,
+                    high: Some(high),
+                },
+                State::PairEscape { .. } | State::PairU { .. } => {
+                    return Err(Custom(QuoteError::LoneSurrogate))
+                }
+            };
+        }
+
+        Ok(Update {
+            consumed: buffer.len(),
+            outcome: Next(self),
+        })
+    }
+
+    fn finalize(self, buffer: &str) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(s) => Ok(Some(s)),
+            Next(qs) if qs.state == State::QuoteSeen => Ok(Some(qs.acc)),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, quoted_string, QuoteConfig, QuoteError};
+
+#[test_case(r#""abc" x"# => Ok(Update { consumed: 5, outcome: Parsed("abc".to_string()) }) ; "plain")]
+#[test_case(r#""a\"b\\c\/\n\t" x"# => Ok(Update { consumed: 15, outcome: Parsed("a\"b\\c/\n\t".to_string()) }) ; "escapes")]
+#[test_case(r#""\u00e9\u4e2d""# => Ok(Update { consumed: 14, outcome: Parsed("é中".to_string()) }) ; "unicode")]
+#[test_case(r#""\ud83c\udf0f""# => Ok(Update { consumed: 14, outcome: Parsed("🌏".to_string()) }) ; "surrogate_pair")]
+#[test_case(r#""🌏""# => Ok(Update { consumed: 6, outcome: Parsed("🌏".to_string()) }) ; "literal_unicode")]
+#[test_case(r#""ab"# => Ok(Update { consumed: 3, outcome: Next(()) }) ; "unterminated")]
+#[test_case(r#""a\"# => Ok(Update { consumed: 3, outcome: Next(()) }) ; "split_escape")]
+#[test_case(r#""\q""# => Err(Custom(QuoteError::InvalidEscape('q'))) ; "invalid_escape")]
+#[test_case(r#""\0""# => Err(Custom(QuoteError::InvalidEscape('0'))) ; "invalid_nul_escape")]
+#[test_case(r#""\'""# => Err(Custom(QuoteError::InvalidEscape('\''))) ; "invalid_single_quote_escape")]
+#[test_case(r#""\u00g0""# => Err(Custom(QuoteError::InvalidHex('g'))) ; "invalid_hex")]
+#[test_case(r#""\ud83c""# => Err(Custom(QuoteError::LoneSurrogate)) ; "lone_high")]
+#[test_case(r#""\ud83c\u0041""# => Err(Custom(QuoteError::LoneSurrogate)) ; "high_then_non_low")]
+#[test_case(r#""\udf0f""# => Err(Custom(QuoteError::LoneSurrogate)) ; "lone_low")]
+#[test_case("abc" => Err(UnexpectedInput) ; "unquoted")]
+fn json(input: &str) -> ParseResult<Update<(), String>, QuoteError> {
+    quoted_string(QuoteConfig::default())
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(r#""a""b","# => Ok(Update { consumed: 6, outcome: Parsed("a\"b".to_string()) }) ; "doubled")]
+#[test_case(r#""a\n""# => Ok(Update { consumed: 5, outcome: Next(()) }) ; "no_escapes")]
+#[test_case(r#""a""# => Ok(Update { consumed: 3, outcome: Next(()) }) ; "closing_undecided")]
+fn csv(input: &str) -> ParseResult<Update<(), String>, QuoteError> {
+    quoted_string(QuoteConfig::csv())
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(QuoteConfig::csv(), r#""a""# => Ok(Some("a".to_string())) ; "csv_closing_at_end")]
+#[test_case(QuoteConfig::default(), r#""a"# => Err(ExpectedMoreInput) ; "unterminated")]
+#[test_case(
+    QuoteConfig { quote: '\'', escape: None, ..QuoteConfig::default() },
+    r"'C:\dir'"
+    => Ok(Some(r"C:\dir".to_string()))
+    ; "literal_string"
+)]
+#[test_case(
+    QuoteConfig { quote: '\'', escape: Some('^'), escapes: &[('t', '\t')], ..QuoteConfig::default() },
+    "'a^'^^^tb'"
+    => Ok(Some("a'^\tb".to_string()))
+    ; "custom_escapes"
+)]
+#[test_case(
+    QuoteConfig { quote: '\'', escape: Some('^'), escapes: &[('t', '\t')], ..QuoteConfig::default() },
+    "'^n'"
+    => Err(Custom(QuoteError::InvalidEscape('n')))
+    ; "custom_escapes_exclude_json"
+)]
+#[test_case(
+    QuoteConfig { quote: '\'', escape: Some('^'), escapes: &[], ..QuoteConfig::default() },
+    r"'^\'"
+    => Err(Custom(QuoteError::InvalidEscape('\\')))
+    ; "custom_escape_char_excludes_backslash"
+)]
+fn finalize(config: QuoteConfig, input: &str) -> ParseResult<Option<String>, QuoteError> {
+    quoted_string(config).finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn escapes_split_across_buffers(bufsize: usize) {
+    let (value, _) = quoted_string(QuoteConfig::default())
+        .then(literal(","))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(
+            r#""é\n\u00e9\ud83c\udf0f\\\"🌎","#.as_bytes(),
+            bufsize,
+        )
+        .unwrap();
+
+    assert_eq!(value, "é\né🌏\\\"🌎");
+}
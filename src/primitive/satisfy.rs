@@ -0,0 +1,124 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Satisfy] parser which yields a single item satisfying `pred`
+pub fn satisfy<B, F>(pred: F) -> Satisfy<B, F>
+where
+    B: ?Sized + ItemBuf,
+    F: FnMut(B::Item) -> bool,
+{
+    Satisfy {
+        pred,
+        phantom: PhantomData,
+    }
+}
+
+/// Construct a [Satisfy] parser which yields a single item contained in `set`, such as `one_of("+-")`
+pub fn one_of<B>(set: &B) -> Satisfy<B, impl Clone + FnMut(B::Item) -> bool + '_>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: PartialEq,
+{
+    satisfy(move |item| contains(set, &item))
+}
+
+/// Construct a [Satisfy] parser which yields a single item not contained in `set`, such as `none_of("\"\\")`
+pub fn none_of<B>(set: &B) -> Satisfy<B, impl Clone + FnMut(B::Item) -> bool + '_>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: PartialEq,
+{
+    satisfy(move |item| !contains(set, &item))
+}
+
+/// Construct a [Satisfy] parser which yields any single item
+pub fn any<B>() -> Satisfy<B, impl Clone + FnMut(B::Item) -> bool>
+where
+    B: ?Sized + ItemBuf,
+{
+    satisfy(|_| true)
+}
+
+fn contains<B>(set: &B, item: &B::Item) -> bool
+where
+    B: ?Sized + ItemBuf,
+    B::Item: PartialEq,
+{
+    set.prefix_len(|x| &x != item) < set.len()
+}
+
+/// A [Satisfy] parser matches a single item which satisfies a predicate
+///
+/// Items are `T` for `[T]` input and `char` for `str` input. An empty buffer, including one trimmed of a partial UTF-8 sequence by [IntoUtf8Parser](crate::parser::IntoUtf8Parser), produces [Next](crate::parser::Outcome::Next).
+pub struct Satisfy<B, F>
+where
+    B: ?Sized,
+{
+    pred: F,
+    phantom: PhantomData<B>,
+}
+
+impl<B, F> Clone for Satisfy<B, F>
+where
+    B: ?Sized,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Satisfy {
+            pred: self.pred.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B, F> fmt::Debug for Satisfy<B, F>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Satisfy").field("pred", &"...").finish()
+    }
+}
+
+impl<B, F> ParserCore<B> for Satisfy<B, F>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: Clone,
+    F: FnMut(B::Item) -> bool,
+{
+    type Output = B::Item;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match buffer.first_item() {
+            None => Ok(Update {
+                consumed: 0,
+                outcome: Next(self),
+            }),
+            Some((item, len)) if (self.pred)(item.clone()) => Ok(Update {
+                consumed: len,
+                outcome: Parsed(item),
+            }),
+            Some(_) => Err(UnexpectedInput),
+        }
+    }
+
+    fn finalize(mut self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        match buffer.first_item() {
+            None => Err(ExpectedMoreInput),
+            Some((item, _)) if (self.pred)(item.clone()) => Ok(Some(item)),
+            Some(_) => Err(UnexpectedInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
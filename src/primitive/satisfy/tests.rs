@@ -0,0 +1,88 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{any, literal, none_of, one_of, satisfy};
+use crate::sequence::SequenceParser;
+
+#[test_case("+1" => Ok(Update { consumed: 1, outcome: Parsed('+') }) ; "plus")]
+#[test_case("-1" => Ok(Update { consumed: 1, outcome: Parsed('-') }) ; "minus")]
+#[test_case("1" => Err(UnexpectedInput) ; "digit")]
+#[test_case("" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn one_of_sign(input: &str) -> ParseResult<Update<(), char>, Infallible> {
+    one_of("+-").feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("é" => Ok(Update { consumed: 2, outcome: Parsed('é') }) ; "multibyte")]
+#[test_case("\"" => Err(UnexpectedInput) ; "quote")]
+#[test_case("\\" => Err(UnexpectedInput) ; "backslash")]
+fn none_of_quote(input: &str) -> ParseResult<Update<(), char>, Infallible> {
+    none_of("\"\\").feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("" => Err(ExpectedMoreInput) ; "empty")]
+#[test_case("🌏" => Ok(Some('🌏')) ; "emoji")]
+fn any_finalize(input: &str) -> ParseResult<Option<char>, Infallible> {
+    any().finalize(input)
+}
+
+#[test]
+fn any_with_split_utf8_sequence() {
+    let bytes = "🌏".as_bytes();
+    let parser = any::<str>().into_utf8_parser();
+
+    let update = parser.feed(&bytes[..2]).unwrap();
+    assert_eq!(update.consumed, 0);
+    let Next(parser) = update.outcome else {
+        panic!("expected Next");
+    };
+
+    let update = parser.feed(bytes).unwrap();
+    assert_eq!(update.consumed, 4);
+    assert!(matches!(update.outcome, Parsed('🌏')));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Word(&'static str),
+}
+
+#[test]
+fn one_of_tokens() {
+    use Token::*;
+
+    let update = one_of([Open, Close].as_slice())
+        .feed([Close, Word("x")].as_slice())
+        .unwrap();
+
+    assert_eq!(update.consumed, 1);
+    assert!(matches!(update.outcome, Parsed(Close)));
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn quoted_chars(bufsize: usize) {
+    let ((_, chars), _) = literal("\"")
+        .then(none_of("\"").repeated().collect::<String>())
+        .then(literal("\""))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("\"Grüße, 🌏!\"".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(chars, "Grüße, 🌏!");
+}
+
+#[test_case(b"7" => matches Ok(Update { consumed: 1, outcome: Parsed(b'7') }) ; "digit")]
+#[test_case(b"x" => matches Err(UnexpectedInput) ; "letter")]
+fn satisfy_byte(input: &[u8]) -> ParseResult<Update<(), u8>, Infallible> {
+    satisfy(|b: u8| b.is_ascii_digit())
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
@@ -0,0 +1,61 @@
+use std::convert::Infallible;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+use crate::primitive::take::prefix_len_of_items;
+
+/// Construct a [Skip] parser which discards exactly `n` items
+pub fn skip(n: usize) -> Skip {
+    Skip::from(n)
+}
+
+/// A [Skip] parser discards exactly `n` items without allocating, consuming input as it arrives
+///
+/// Items are `char`s for `str` input.
+#[derive(Copy, Clone, Debug)]
+pub struct Skip {
+    remaining: usize,
+}
+
+impl From<usize> for Skip {
+    fn from(n: usize) -> Self {
+        Skip { remaining: n }
+    }
+}
+
+impl<B> ParserCore<B> for Skip
+where
+    B: ?Sized + ItemBuf,
+{
+    type Output = ();
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let consumed = prefix_len_of_items(buffer, &mut self.remaining);
+
+        Ok(Update {
+            consumed,
+            outcome: if self.remaining == 0 {
+                Parsed(())
+            } else {
+                Next(self)
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        match self.feed(buffer)?.outcome {
+            Parsed(()) => Ok(Some(())),
+            _ => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,45 @@
+use std::convert::Infallible;
+use std::io::Read;
+
+use test_case::test_case;
+
+use crate::buffer::BufferManager;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ParserCore, PushParser, Update};
+use crate::primitive::{literal, skip};
+
+#[test_case(3, b"abcdef" => Ok(Update { consumed: 3, outcome: Parsed(()) }) ; "prefix")]
+#[test_case(3, b"ab" => Ok(Update { consumed: 2, outcome: Next(()) }) ; "short")]
+#[test_case(0, b"" => Ok(Update { consumed: 0, outcome: Parsed(()) }) ; "zero")]
+fn skip_bytes(n: usize, input: &[u8]) -> ParseResult<Update<(), ()>, Infallible> {
+    skip(n).feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test]
+fn skip_never_grows_buffer() {
+    const BUFSIZE: usize = 64;
+
+    let mut input = vec![0u8; 1 << 16];
+    input.extend_from_slice(b"END");
+    let mut reader = input.as_slice();
+
+    let mut bufmgr = BufferManager::from(vec![0u8; BUFSIZE]);
+    let mut parser = skip(1 << 16).then(literal(b"END".as_slice()));
+
+    loop {
+        let writeslice = bufmgr.get_write_slice();
+        assert!(writeslice.len() <= BUFSIZE);
+
+        let readcnt = reader.read(writeslice).unwrap();
+
+        match bufmgr.process_write(parser, readcnt).unwrap() {
+            Next(next) => parser = next,
+            Parsed(((), end)) => {
+                assert_eq!(end, b"END");
+                break;
+            }
+        }
+    }
+    assert_eq!(bufmgr.offset(), (1 << 16) + 3);
+}
@@ -0,0 +1,114 @@
+use std::convert::Infallible;
+use std::fmt;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Take] parser which yields exactly `n` owned items
+pub fn take<B>(n: usize) -> Take<B>
+where
+    B: ?Sized + ItemBuf,
+{
+    Take::from(n)
+}
+
+/// A [Take] parser accumulates exactly `n` items, consuming input as it arrives
+///
+/// Items are `char`s for `str` input.
+pub struct Take<B>
+where
+    B: ?Sized + ItemBuf,
+{
+    remaining: usize,
+    acc: B::Accumulator,
+}
+
+impl<B> From<usize> for Take<B>
+where
+    B: ?Sized + ItemBuf,
+{
+    fn from(n: usize) -> Self {
+        Take {
+            remaining: n,
+            acc: B::Accumulator::default(),
+        }
+    }
+}
+
+impl<B> Clone for Take<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: Clone,
+{
+    fn clone(&self) -> Self {
+        Take {
+            remaining: self.remaining,
+            acc: self.acc.clone(),
+        }
+    }
+}
+
+impl<B> fmt::Debug for Take<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Take")
+            .field("remaining", &self.remaining)
+            .field("acc", &self.acc)
+            .finish()
+    }
+}
+
+impl<B> ParserCore<B> for Take<B>
+where
+    B: ?Sized + ItemBuf,
+{
+    type Output = B::Accumulator;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let consumed = prefix_len_of_items(buffer, &mut self.remaining);
+        buffer.split_at(consumed).0.append_to(&mut self.acc);
+
+        Ok(Update {
+            consumed,
+            outcome: if self.remaining == 0 {
+                Parsed(self.acc)
+            } else {
+                Next(self)
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        match self.feed(buffer)?.outcome {
+            Parsed(acc) => Ok(Some(acc)),
+            _ => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+/// The length in buffer units of up to `remaining` items of `buffer`, decrementing `remaining` by the number of items
+pub(super) fn prefix_len_of_items<B>(buffer: &B, remaining: &mut usize) -> usize
+where
+    B: ?Sized + ItemBuf,
+{
+    buffer.prefix_len(|_| {
+        let more = *remaining > 0;
+        if more {
+            *remaining -= 1;
+        }
+        more
+    })
+}
+
+#[cfg(test)]
+mod tests;
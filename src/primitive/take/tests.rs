@@ -0,0 +1,53 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, take};
+
+#[test_case(3, "abcdef" => Ok(Update { consumed: 3, outcome: Parsed("abc".to_string()) }) ; "prefix")]
+#[test_case(3, "ab" => Ok(Update { consumed: 2, outcome: Next(()) }) ; "short")]
+#[test_case(2, "é🌏x" => Ok(Update { consumed: 6, outcome: Parsed("é🌏".to_string()) }) ; "chars")]
+#[test_case(0, "abc" => Ok(Update { consumed: 0, outcome: Parsed(String::new()) }) ; "zero")]
+fn take_str(n: usize, input: &str) -> ParseResult<Update<(), String>, Infallible> {
+    take(n).feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(3, b"abc" => Ok(Some(b"abc".to_vec())) ; "exact")]
+#[test_case(3, b"ab" => Err(ExpectedMoreInput) ; "short")]
+fn take_bytes_finalize(n: usize, input: &[u8]) -> ParseResult<Option<Vec<u8>>, Infallible> {
+    take(n).finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(7)]
+#[test_case(64)]
+#[test_case(1<<14)]
+fn take_larger_than_buffer(bufsize: usize) {
+    let mut input = vec![b'x'; 5000];
+    input.extend_from_slice(b"END");
+
+    let (taken, _) = take(5000)
+        .then(literal(b"END".as_slice()))
+        .parse_reader_with_bufsize::<_, Infallible>(input.as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(taken, vec![b'x'; 5000]);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn take_utf8_chars(bufsize: usize) {
+    let (taken, rest) = take(3)
+        .then(take(2))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("🌏é!ab".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(taken, "🌏é!");
+    assert_eq!(rest, "ab");
+}
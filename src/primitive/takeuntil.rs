@@ -0,0 +1,118 @@
+use std::convert::Infallible;
+use std::fmt;
+
+use memchr::memmem::Finder;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [TakeUntil] parser which yields the owned input preceding `delim`
+pub fn take_until<B>(delim: &B) -> TakeUntil<'_, B>
+where
+    B: ?Sized + ItemBuf + AsRef<[u8]>,
+{
+    TakeUntil::from(delim)
+}
+
+/// A [TakeUntil] parser accumulates input up to, but not including, a delimiter
+///
+/// Each buffer is searched with a substring finder. When the delimiter is not found, all input is consumed except for the longest suffix which may begin a delimiter split across buffers.
+pub struct TakeUntil<'s, B>
+where
+    B: ?Sized + ItemBuf,
+{
+    delim: &'s B,
+    finder: Finder<'s>,
+    acc: B::Accumulator,
+}
+
+impl<'s, B> From<&'s B> for TakeUntil<'s, B>
+where
+    B: ?Sized + ItemBuf + AsRef<[u8]>,
+{
+    fn from(delim: &'s B) -> Self {
+        TakeUntil {
+            delim,
+            finder: Finder::new(delim.as_ref()),
+            acc: B::Accumulator::default(),
+        }
+    }
+}
+
+impl<B> Clone for TakeUntil<'_, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: Clone,
+{
+    fn clone(&self) -> Self {
+        TakeUntil {
+            delim: self.delim,
+            finder: self.finder.clone(),
+            acc: self.acc.clone(),
+        }
+    }
+}
+
+impl<B> fmt::Debug for TakeUntil<'_, B>
+where
+    B: ?Sized + ItemBuf + fmt::Debug,
+    B::Accumulator: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeUntil")
+            .field("delim", &self.delim)
+            .field("acc", &self.acc)
+            .finish()
+    }
+}
+
+impl<B> ParserCore<B> for TakeUntil<'_, B>
+where
+    B: ?Sized + ItemBuf + AsRef<[u8]>,
+{
+    type Output = B::Accumulator;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        if let Some(found) = self.finder.find(buffer.as_ref()) {
+            buffer.split_at(found).0.append_to(&mut self.acc);
+            Ok(Update {
+                consumed: found,
+                outcome: Parsed(self.acc),
+            })
+        } else {
+            let consumed = buffer.len() - partial_delim_len(buffer.as_ref(), self.delim.as_ref());
+            buffer.split_at(consumed).0.append_to(&mut self.acc);
+            Ok(Update {
+                consumed,
+                outcome: Next(self),
+            })
+        }
+    }
+
+    fn finalize(mut self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let found = self.finder.find(buffer.as_ref()).ok_or(ExpectedMoreInput)?;
+
+        buffer.split_at(found).0.append_to(&mut self.acc);
+        Ok(Some(self.acc))
+    }
+}
+
+/// The length of the longest suffix of `haystack` which is a proper prefix of `delim`
+///
+/// Because `delim` begins at a UTF-8 char boundary, so does such a suffix of `str` input.
+pub(super) fn partial_delim_len(haystack: &[u8], delim: &[u8]) -> usize {
+    let maxlen = std::cmp::min(haystack.len(), delim.len().saturating_sub(1));
+
+    (1..=maxlen)
+        .rev()
+        .find(|&len| haystack.ends_with(&delim[..len]))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests;
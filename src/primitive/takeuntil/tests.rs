@@ -0,0 +1,61 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, take_until};
+
+#[test_case("Host: x\r\n\r\nbody" => Ok(Update { consumed: 7, outcome: Parsed("Host: x".to_string()) }) ; "found")]
+#[test_case("Host: x\r\n\r" => Ok(Update { consumed: 7, outcome: Next(()) }) ; "partial_delim_retained")]
+#[test_case("Host: x\r\n" => Ok(Update { consumed: 7, outcome: Next(()) }) ; "shorter_partial_delim_retained")]
+#[test_case("Host: x\n" => Ok(Update { consumed: 8, outcome: Next(()) }) ; "not_found")]
+#[test_case("\r\n\r\n" => Ok(Update { consumed: 0, outcome: Parsed(String::new()) }) ; "immediate")]
+#[test_case("" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn take_until_crlfcrlf(input: &str) -> ParseResult<Update<(), String>, Infallible> {
+    take_until("\r\n\r\n")
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("abc" => Err(ExpectedMoreInput) ; "missing")]
+#[test_case("abc--x" => Ok(Some("abc".to_string())) ; "found")]
+fn take_until_finalize(input: &str) -> ParseResult<Option<String>, Infallible> {
+    take_until("--x").finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(7)]
+#[test_case(1<<14)]
+fn multipart_boundary(bufsize: usize) {
+    const INPUT: &[u8] = b"part one --boun--bound --boundarypart two--boundary";
+
+    let (first, ((_, second), _)) = take_until(b"--boundary".as_slice())
+        .then(
+            literal(b"--boundary".as_slice())
+                .then(take_until(b"--boundary".as_slice()))
+                .then(literal(b"--boundary".as_slice())),
+        )
+        .parse_reader_with_bufsize::<_, Infallible>(INPUT, bufsize)
+        .unwrap();
+
+    assert_eq!(first, b"part one --boun--bound ");
+    assert_eq!(second, b"part two");
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn utf8_delimiter(bufsize: usize) {
+    let (content, _) = take_until("🌏🌏")
+        .then(literal("🌏🌏"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("héllo 🌏 wörld🌏🌏".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(content, "héllo 🌏 wörld");
+}
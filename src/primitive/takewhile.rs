@@ -0,0 +1,139 @@
+use std::convert::Infallible;
+use std::fmt;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [TakeWhile] parser which yields the owned prefix of items satisfying `pred`, which may be empty
+pub fn take_while<B, F>(pred: F) -> TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+    F: FnMut(B::Item) -> bool,
+{
+    TakeWhile::new(pred, false)
+}
+
+/// Construct a [TakeWhile] parser which yields the owned prefix of items satisfying `pred`, which must be non-empty
+pub fn take_while1<B, F>(pred: F) -> TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+    F: FnMut(B::Item) -> bool,
+{
+    TakeWhile::new(pred, true)
+}
+
+/// A [TakeWhile] parser accumulates items while they satisfy a predicate
+///
+/// The end of a match is only decided when an unsatisfying item is fed or upon [ParserCore::finalize], so a buffer of all satisfying items produces [Next](crate::parser::Outcome::Next).
+pub struct TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+{
+    pred: F,
+    nonempty: bool,
+    matchcnt: usize,
+    acc: B::Accumulator,
+}
+
+impl<B, F> TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+    F: FnMut(B::Item) -> bool,
+{
+    fn new(pred: F, nonempty: bool) -> Self {
+        TakeWhile {
+            pred,
+            nonempty,
+            matchcnt: 0,
+            acc: B::Accumulator::default(),
+        }
+    }
+
+    /// Accumulate the matching prefix of `buffer`, returning its length and the remaining suffix
+    fn scan<'b>(&mut self, buffer: &'b B) -> (usize, &'b B) {
+        let len = buffer.prefix_len(&mut self.pred);
+        let (matched, suffix) = buffer.split_at(len);
+        matched.append_to(&mut self.acc);
+        self.matchcnt += len;
+        (len, suffix)
+    }
+}
+
+impl<B, F> Clone for TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        TakeWhile {
+            pred: self.pred.clone(),
+            nonempty: self.nonempty,
+            matchcnt: self.matchcnt,
+            acc: self.acc.clone(),
+        }
+    }
+}
+
+impl<B, F> fmt::Debug for TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeWhile")
+            .field("pred", &"...")
+            .field("nonempty", &self.nonempty)
+            .field("acc", &self.acc)
+            .finish()
+    }
+}
+
+impl<B, F> ParserCore<B> for TakeWhile<B, F>
+where
+    B: ?Sized + ItemBuf,
+    F: FnMut(B::Item) -> bool,
+{
+    type Output = B::Accumulator;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let (consumed, suffix) = self.scan(buffer);
+
+        if suffix.is_empty() {
+            // The match may continue in the next buffer:
+            Ok(Update {
+                consumed,
+                outcome: Next(self),
+            })
+        } else if self.nonempty && self.matchcnt == 0 {
+            Err(UnexpectedInput)
+        } else {
+            Ok(Update {
+                consumed,
+                outcome: Parsed(self.acc),
+            })
+        }
+    }
+
+    fn finalize(mut self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let (_, suffix) = self.scan(buffer);
+
+        if self.nonempty && self.matchcnt == 0 {
+            if suffix.is_empty() {
+                Err(ExpectedMoreInput)
+            } else {
+                Err(UnexpectedInput)
+            }
+        } else {
+            Ok(Some(self.acc))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
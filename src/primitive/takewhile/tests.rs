@@ -0,0 +1,70 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::{literal, take_while, take_while1};
+
+#[test_case("abc123" => Ok(Update { consumed: 3, outcome: Parsed("abc".to_string()) }) ; "prefix")]
+#[test_case("abc" => Ok(Update { consumed: 3, outcome: Next(()) }) ; "all_match")]
+#[test_case("123" => Ok(Update { consumed: 0, outcome: Parsed(String::new()) }) ; "no_match")]
+#[test_case("" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+#[test_case("é🌏!" => Ok(Update { consumed: 2, outcome: Parsed("é".to_string()) }) ; "multibyte")]
+fn take_while_alphabetic_str(input: &str) -> ParseResult<Update<(), String>, Infallible> {
+    take_while(char::is_alphabetic)
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(b"12;" => Ok(Update { consumed: 2, outcome: Parsed(b"12".to_vec()) }) ; "prefix")]
+#[test_case(b"12" => Ok(Update { consumed: 2, outcome: Next(()) }) ; "all_match")]
+#[test_case(b";" => Err(UnexpectedInput) ; "no_match")]
+#[test_case(b"" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn take_while1_digit_bytes(input: &[u8]) -> ParseResult<Update<(), Vec<u8>>, Infallible> {
+    take_while1(|b: u8| b.is_ascii_digit())
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("" => Ok(String::new()) ; "empty_take_while")]
+#[test_case("abc" => Ok("abc".to_string()) ; "all_match")]
+fn take_while_finalize(input: &str) -> ParseResult<String, Infallible> {
+    take_while(char::is_alphabetic)
+        .finalize(input)
+        .map(Option::unwrap)
+}
+
+#[test_case("" => Err(ExpectedMoreInput) ; "empty")]
+#[test_case("!" => Err(UnexpectedInput) ; "no_match")]
+fn take_while1_finalize(input: &str) -> ParseResult<Option<String>, Infallible> {
+    take_while1(char::is_alphabetic).finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn take_while_across_buffers(bufsize: usize) {
+    let (word, (digits, _)) = take_while1(|c: char| !c.is_ascii_digit())
+        .then(take_while1(|c: char| c.is_ascii_digit()).then(literal(";")))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("Grüße 🌏 12345;".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!(word, "Grüße 🌏 ");
+    assert_eq!(digits, "12345");
+}
+
+#[test_case(1)]
+#[test_case(4)]
+#[test_case(1<<14)]
+fn take_while_at_end_of_input(bufsize: usize) {
+    let digits = take_while1(|b: u8| b.is_ascii_digit())
+        .parse_reader_with_bufsize::<_, Infallible>(b"8675309".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(digits, b"8675309");
+}
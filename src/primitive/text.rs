@@ -0,0 +1,14 @@
+//! Primitives for text grammars: [Whitespace], [LineEnding], [Line], comments, and [lexeme]
+//!
+//! These accept both `str` and `[u8]` input. Over `[u8]`, whitespace is ASCII whitespace, while over `str` it is Unicode whitespace.
+mod comment;
+mod lexeme;
+mod line;
+mod lineending;
+mod whitespace;
+
+pub use self::comment::{block_comment, line_comment, BlockComment, LineComment};
+pub use self::lexeme::{lexeme, Lexeme};
+pub use self::line::{line, Line};
+pub use self::lineending::{line_ending, LineEnding};
+pub use self::whitespace::{whitespace0, whitespace1, TextItem, Whitespace};
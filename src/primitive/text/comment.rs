@@ -0,0 +1,194 @@
+use std::convert::Infallible;
+use std::fmt;
+
+use memchr::memchr2;
+use memchr::memmem::Finder;
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+use crate::primitive::takeuntil::partial_delim_len;
+use crate::primitive::{literal, Literal};
+
+/// Construct a [LineComment] parser which skips from `prefix` up to a line ending, such as `line_comment("#")`
+pub fn line_comment<B>(prefix: &B) -> LineComment<'_, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    LineComment {
+        prefix: literal(prefix),
+        in_body: false,
+    }
+}
+
+/// Construct a [BlockComment] parser which skips from `open` through `close`, such as `block_comment("/*", "*/")`
+pub fn block_comment<'s, B>(open: &'s B, close: &'s B) -> BlockComment<'s, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    BlockComment {
+        open: literal(open),
+        close,
+        finder: Finder::new(close.as_ref()),
+        in_body: false,
+    }
+}
+
+/// A [LineComment] parser skips a prefix and the rest of its line, yielding `()`
+///
+/// The line ending is not consumed, so a grammar may treat it as it would any other. A comment on the last line ends at the end of input.
+#[derive(Clone, Debug)]
+pub struct LineComment<'s, B>
+where
+    B: ?Sized,
+{
+    prefix: Literal<'s, B>,
+    in_body: bool,
+}
+
+impl<B> ParserCore<B> for LineComment<'_, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    type Output = ();
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        if !self.in_body {
+            let Update { consumed, outcome } = self.prefix.clone().feed(buffer)?;
+            return match outcome {
+                Next(prefix) => Ok(Update {
+                    consumed,
+                    outcome: Next(LineComment { prefix, ..self }),
+                }),
+                Parsed(_) => {
+                    self.in_body = true;
+                    let subup = self.feed(buffer.drop_up_to(consumed))?;
+                    Ok(Update {
+                        consumed: consumed + subup.consumed,
+                        outcome: subup.outcome,
+                    })
+                }
+            };
+        }
+
+        Ok(match memchr2(b'\r', b'\n', buffer.as_ref()) {
+            Some(found) => Update {
+                consumed: found,
+                outcome: Parsed(()),
+            },
+            None => Update {
+                consumed: buffer.len(),
+                outcome: Next(self),
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(()) => Ok(Some(())),
+            Next(comment) if comment.in_body => Ok(Some(())),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+/// A [BlockComment] parser skips input from an opening delimiter through a closing delimiter, yielding `()`
+///
+/// Comments do not nest. As with [TakeUntil](crate::primitive::TakeUntil), only a suffix which may begin a closing delimiter is retained between buffers, and an unterminated comment produces [ExpectedMoreInput](crate::error::ParseError::ExpectedMoreInput).
+pub struct BlockComment<'s, B>
+where
+    B: ?Sized,
+{
+    open: Literal<'s, B>,
+    close: &'s B,
+    finder: Finder<'s>,
+    in_body: bool,
+}
+
+impl<B> Clone for BlockComment<'_, B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        BlockComment {
+            open: self.open.clone(),
+            close: self.close,
+            finder: self.finder.clone(),
+            in_body: self.in_body,
+        }
+    }
+}
+
+impl<B> fmt::Debug for BlockComment<'_, B>
+where
+    B: ?Sized + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockComment")
+            .field("open", &self.open)
+            .field("close", &self.close)
+            .field("in_body", &self.in_body)
+            .finish()
+    }
+}
+
+impl<B> ParserCore<B> for BlockComment<'_, B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    type Output = ();
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        if !self.in_body {
+            let Update { consumed, outcome } = self.open.clone().feed(buffer)?;
+            return match outcome {
+                Next(open) => Ok(Update {
+                    consumed,
+                    outcome: Next(BlockComment { open, ..self }),
+                }),
+                Parsed(_) => {
+                    self.in_body = true;
+                    let subup = self.feed(buffer.drop_up_to(consumed))?;
+                    Ok(Update {
+                        consumed: consumed + subup.consumed,
+                        outcome: subup.outcome,
+                    })
+                }
+            };
+        }
+
+        let bytes = buffer.as_ref();
+        let close = self.close.as_ref();
+        Ok(match self.finder.find(bytes) {
+            Some(found) => Update {
+                consumed: found + close.len(),
+                outcome: Parsed(()),
+            },
+            None => Update {
+                consumed: bytes.len() - partial_delim_len(bytes, close),
+                outcome: Next(self),
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        match self.feed(buffer)?.outcome {
+            Parsed(()) => Ok(Some(())),
+            _ => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
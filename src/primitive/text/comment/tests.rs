@@ -0,0 +1,60 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::literal;
+use crate::primitive::text::{block_comment, line_comment, line_ending};
+
+#[test_case("# note\nx" => Ok(Update { consumed: 6, outcome: Parsed(()) }) ; "to_line_ending")]
+#[test_case("# note" => Ok(Update { consumed: 6, outcome: Next(()) }) ; "undecided")]
+#[test_case("x" => Err(UnexpectedInput) ; "mismatch")]
+fn line_comment_str(input: &str) -> ParseResult<Update<(), ()>, Infallible> {
+    line_comment("#").feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("// last" => Ok(Some(())) ; "end_of_input")]
+#[test_case("/" => Err(ExpectedMoreInput) ; "partial_prefix")]
+fn line_comment_finalize(input: &str) -> ParseResult<Option<()>, Infallible> {
+    line_comment("//").finalize(input)
+}
+
+#[test_case("/* a */x" => Ok(Update { consumed: 7, outcome: Parsed(()) }) ; "complete")]
+#[test_case("/* a *" => Ok(Update { consumed: 5, outcome: Next(()) }) ; "partial_close_retained")]
+#[test_case("/**/" => Ok(Update { consumed: 4, outcome: Parsed(()) }) ; "empty")]
+#[test_case("/* /* */ */" => Ok(Update { consumed: 8, outcome: Parsed(()) }) ; "no_nesting")]
+#[test_case("x" => Err(UnexpectedInput) ; "mismatch")]
+fn block_comment_str(input: &str) -> ParseResult<Update<(), ()>, Infallible> {
+    block_comment("/*", "*/")
+        .feed(input)
+        .map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(b"/* a" => Err(ExpectedMoreInput) ; "unterminated")]
+#[test_case(b"/* a */" => Ok(Some(())) ; "complete")]
+fn block_comment_finalize(input: &[u8]) -> ParseResult<Option<()>, Infallible> {
+    block_comment(b"/*".as_slice(), b"*/".as_slice()).finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn comments(bufsize: usize) {
+    let parsed = literal("a")
+        .then(block_comment("{-", "-}"))
+        .then(literal("b"))
+        .then(line_comment("--"))
+        .then(line_ending())
+        .then(literal("c"))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(
+            "a{- 🌏 - } -}b-- note 🌎\r\nc".as_bytes(),
+            bufsize,
+        );
+
+    assert!(parsed.is_ok());
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use crate::buffer::ItemBuf;
+use crate::combinator::Then;
+use crate::error::{ParseResult, ParseResultExt, ParseResultUpdateExt};
+use crate::parser::{Context, ParserCore, PushParser, Update};
+use crate::primitive::text::{whitespace0, TextItem, Whitespace};
+
+/// Construct a [Lexeme] parser which parses `parser` then skips any trailing whitespace
+pub fn lexeme<B, P>(parser: P) -> Lexeme<P, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+    P: ParserCore<B>,
+{
+    Lexeme(parser.then(whitespace0()))
+}
+
+/// A [Lexeme] parser yields the output of `P` after skipping any trailing whitespace
+///
+/// Wrapping each token of a grammar in [lexeme], along with skipping any leading whitespace once, frees the rest of the grammar from handling whitespace.
+pub struct Lexeme<P, B>(Then<P, Whitespace<B>, B>)
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+    P: ParserCore<B>;
+
+impl<P, B> Clone for Lexeme<P, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+    P: ParserCore<B>,
+    Then<P, Whitespace<B>, B>: Clone,
+{
+    fn clone(&self) -> Self {
+        Lexeme(self.0.clone())
+    }
+}
+
+impl<P, B> fmt::Debug for Lexeme<P, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+    P: ParserCore<B>,
+    Then<P, Whitespace<B>, B>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Lexeme").field(&self.0).finish()
+    }
+}
+
+impl<P, B> ParserCore<B> for Lexeme<P, B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+    P: ParserCore<B>,
+{
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.0
+            .feed_with(ctx, buffer)
+            .map_err_custom(|e| e.left_or_else(|never| match never {}))
+            .map_next(Lexeme)
+            .map_output(|(output, ())| output)
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.0
+            .finalize_with(ctx, buffer)
+            .map_err_custom(|e| e.left_or_else(|never| match never {}))
+            .map(|optval| optval.map(|(output, ())| output))
+    }
+
+    fn finalize_consumed(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error> {
+        self.0
+            .finalize_consumed(ctx, buffer)
+            .map_err_custom(|e| e.left_or_else(|never| match never {}))
+            .map(|optval| optval.map(|(consumed, (output, ()))| (consumed, output)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
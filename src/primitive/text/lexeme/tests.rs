@@ -0,0 +1,31 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::Custom;
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser};
+use crate::primitive::text::{lexeme, whitespace0};
+use crate::primitive::{literal, uint, NumberError};
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn tokens(bufsize: usize) {
+    let ((((_, x), _), y), _) = whitespace0()
+        .then(lexeme(literal("(")))
+        .then(lexeme(uint::<u32, _>()))
+        .then(lexeme(literal(",")))
+        .then(lexeme(uint::<u32, _>()))
+        .then(lexeme(literal(")")))
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>("  ( 12 ,\n\t34 ) ".as_bytes(), bufsize)
+        .unwrap();
+
+    assert_eq!((x, y), (12, 34));
+}
+
+#[test]
+fn error_passes_through() {
+    let parsed = lexeme(uint::<u8, _>()).finalize("300 ");
+    assert_eq!(parsed, Err(Custom(NumberError::Overflow)));
+}
@@ -0,0 +1,130 @@
+use std::convert::Infallible;
+use std::fmt;
+
+use memchr::memchr2;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+use crate::primitive::text::{line_ending, LineEnding};
+
+/// Construct a [Line] parser which yields the owned text up to a line ending, such as a `String` for `str` input
+pub fn line<B>() -> Line<B>
+where
+    B: ?Sized + ItemBuf + AsRef<[u8]>,
+{
+    Line {
+        acc: B::Accumulator::default(),
+        textlen: 0,
+        ending: None,
+    }
+}
+
+/// A [Line] parser accumulates input up to a [LineEnding], which it consumes but excludes from its output
+///
+/// At the end of input, a final line without a line ending is yielded if it is non-empty.
+pub struct Line<B>
+where
+    B: ?Sized + ItemBuf,
+{
+    acc: B::Accumulator,
+    textlen: usize,
+    ending: Option<LineEnding<B>>,
+}
+
+impl<B> Clone for Line<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: Clone,
+{
+    fn clone(&self) -> Self {
+        Line {
+            acc: self.acc.clone(),
+            textlen: self.textlen,
+            ending: self.ending.clone(),
+        }
+    }
+}
+
+impl<B> fmt::Debug for Line<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Accumulator: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Line")
+            .field("acc", &self.acc)
+            .field("textlen", &self.textlen)
+            .field("ending", &self.ending)
+            .finish()
+    }
+}
+
+impl<B> ParserCore<B> for Line<B>
+where
+    B: ?Sized + ItemBuf + AsRef<[u8]>,
+{
+    type Output = B::Accumulator;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let Some(ending) = self.ending.take() else {
+            let Some(found) = memchr2(b'\r', b'\n', buffer.as_ref()) else {
+                buffer.append_to(&mut self.acc);
+                self.textlen += buffer.len();
+                return Ok(Update {
+                    consumed: buffer.len(),
+                    outcome: Next(self),
+                });
+            };
+
+            // Line endings are ASCII, so `found` is a char boundary for `str`:
+            let (text, rest) = buffer.split_at(found);
+            text.append_to(&mut self.acc);
+            self.textlen += found;
+            self.ending = Some(line_ending());
+
+            let Update { consumed, outcome } = self.feed(rest)?;
+            return Ok(Update {
+                consumed: found + consumed,
+                outcome,
+            });
+        };
+
+        let Update { consumed, outcome } = ending.feed(buffer)?;
+        Ok(Update {
+            consumed,
+            outcome: match outcome {
+                Next(ending) => Next(Line {
+                    ending: Some(ending),
+                    ..self
+                }),
+                Parsed(()) => Parsed(self.acc),
+            },
+        })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(acc) => Ok(Some(acc)),
+            // Both states of `Next` consume the whole buffer:
+            Next(Line {
+                acc,
+                ending: Some(ending),
+                ..
+            }) => ending
+                .finalize(buffer.drop_up_to(buffer.len()))
+                .map(|_| Some(acc)),
+            Next(Line { acc, textlen, .. }) if textlen > 0 => Ok(Some(acc)),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
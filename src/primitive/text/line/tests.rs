@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::text::line;
+use crate::sequence::SequenceParser;
+
+#[test_case("abc\ndef" => Ok(Update { consumed: 4, outcome: Parsed("abc".to_string()) }) ; "lf")]
+#[test_case("abc\r\ndef" => Ok(Update { consumed: 5, outcome: Parsed("abc".to_string()) }) ; "crlf")]
+#[test_case("abc\r" => Ok(Update { consumed: 4, outcome: Next(()) }) ; "trailing_cr")]
+#[test_case("abc" => Ok(Update { consumed: 3, outcome: Next(()) }) ; "undecided")]
+#[test_case("\n" => Ok(Update { consumed: 1, outcome: Parsed(String::new()) }) ; "blank")]
+fn line_str(input: &str) -> ParseResult<Update<(), String>, Infallible> {
+    line().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("abc" => Ok(Some("abc".to_string())) ; "unterminated")]
+#[test_case("abc\r" => Ok(Some("abc".to_string())) ; "trailing_cr")]
+#[test_case("" => Err(ExpectedMoreInput) ; "empty")]
+fn line_finalize(input: &str) -> ParseResult<Option<String>, Infallible> {
+    line().finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(1<<14)]
+fn lines(bufsize: usize) {
+    let lines = line()
+        .repeated()
+        .collect::<Vec<String>>()
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(
+            "🌏 one\r\ntwo\rthree\n\nfour".as_bytes(),
+            bufsize,
+        )
+        .unwrap();
+
+    assert_eq!(lines, vec!["🌏 one", "two", "three", "", "four"]);
+}
+
+#[test_case(1)]
+#[test_case(1<<14)]
+fn byte_lines(bufsize: usize) {
+    let lines = line()
+        .repeated()
+        .collect::<Vec<Vec<u8>>>()
+        .parse_reader_with_bufsize::<_, Infallible>(b"a\nb\n".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(lines, vec![b"a".to_vec(), b"b".to_vec()]);
+}
@@ -0,0 +1,92 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [LineEnding] parser which matches `"\n"`, `"\r\n"`, or a bare `"\r"`
+pub fn line_ending<B>() -> LineEnding<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    LineEnding {
+        cr: false,
+        phantom: PhantomData,
+    }
+}
+
+/// A [LineEnding] parser matches `"\n"`, `"\r\n"`, or a bare `"\r"`, yielding `()`
+///
+/// A `'\r'` at the end of a buffer is consumed, and then the parser waits for the next buffer or [ParserCore::finalize] to decide whether a `'\n'` follows.
+pub struct LineEnding<B>
+where
+    B: ?Sized,
+{
+    cr: bool,
+    phantom: PhantomData<B>,
+}
+
+impl<B> Clone for LineEnding<B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        LineEnding {
+            cr: self.cr,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B> fmt::Debug for LineEnding<B>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineEnding").field("cr", &self.cr).finish()
+    }
+}
+
+impl<B> ParserCore<B> for LineEnding<B>
+where
+    B: ?Sized + BufRef + AsRef<[u8]>,
+{
+    type Output = ();
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let bytes = buffer.as_ref();
+        let (consumed, outcome) = match (self.cr, bytes) {
+            (_, []) => (0, Next(self)),
+            (_, [b'\n', ..]) => (1, Parsed(())),
+            (false, [b'\r', b'\n', ..]) => (2, Parsed(())),
+            (false, [b'\r']) => {
+                self.cr = true;
+                (1, Next(self))
+            }
+            (false, [b'\r', ..]) => (1, Parsed(())),
+            (false, _) => return Err(UnexpectedInput),
+            (true, _) => (0, Parsed(())),
+        };
+
+        Ok(Update { consumed, outcome })
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(()) => Ok(Some(())),
+            Next(le) if le.cr => Ok(Some(())),
+            Next(_) => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
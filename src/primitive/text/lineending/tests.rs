@@ -0,0 +1,52 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, Update};
+use crate::primitive::literal;
+use crate::primitive::text::line_ending;
+
+#[test_case("\nx" => Ok(Update { consumed: 1, outcome: Parsed(()) }) ; "lf")]
+#[test_case("\r\nx" => Ok(Update { consumed: 2, outcome: Parsed(()) }) ; "crlf")]
+#[test_case("\rx" => Ok(Update { consumed: 1, outcome: Parsed(()) }) ; "bare_cr")]
+#[test_case("\r" => Ok(Update { consumed: 1, outcome: Next(()) }) ; "trailing_cr_held")]
+#[test_case("" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+#[test_case("x" => Err(UnexpectedInput) ; "mismatch")]
+fn line_ending_str(input: &str) -> ParseResult<Update<(), ()>, Infallible> {
+    line_ending().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("\n" => Ok(Update { consumed: 1, outcome: Parsed(()) }) ; "lf")]
+#[test_case("x" => Ok(Update { consumed: 0, outcome: Parsed(()) }) ; "other")]
+#[test_case("" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn line_ending_after_cr(input: &str) -> ParseResult<Update<(), ()>, Infallible> {
+    let Update { outcome, .. } = line_ending().feed("\r").unwrap();
+    let Next(le) = outcome else {
+        panic!("expected Next");
+    };
+    le.feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(b"\r" => Ok(Some(())) ; "bare_cr")]
+#[test_case(b"" => Err(ExpectedMoreInput) ; "empty")]
+fn line_ending_finalize(input: &[u8]) -> ParseResult<Option<()>, Infallible> {
+    line_ending().finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn crlf_split(bufsize: usize) {
+    let parsed = literal(b"a".as_slice())
+        .then(line_ending())
+        .then(literal(b"b".as_slice()))
+        .then(line_ending())
+        .then(literal(b"c".as_slice()))
+        .then(line_ending())
+        .parse_reader_with_bufsize::<_, Infallible>(b"a\r\nb\rc\r".as_slice(), bufsize);
+
+    assert!(parsed.is_ok());
+}
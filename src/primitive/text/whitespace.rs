@@ -0,0 +1,137 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::ItemBuf;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Whitespace] parser which skips zero or more whitespace items
+pub fn whitespace0<B>() -> Whitespace<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+{
+    Whitespace::new(false)
+}
+
+/// Construct a [Whitespace] parser which skips one or more whitespace items
+pub fn whitespace1<B>() -> Whitespace<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+{
+    Whitespace::new(true)
+}
+
+/// Items of text input: `u8` for `[u8]` and `char` for `str`
+pub trait TextItem {
+    /// Whether this item is whitespace: ASCII whitespace for `u8` and Unicode whitespace for `char`
+    fn is_whitespace(&self) -> bool;
+}
+
+impl TextItem for u8 {
+    fn is_whitespace(&self) -> bool {
+        self.is_ascii_whitespace()
+    }
+}
+
+impl TextItem for char {
+    fn is_whitespace(&self) -> bool {
+        char::is_whitespace(*self)
+    }
+}
+
+/// A [Whitespace] parser skips whitespace, yielding `()`
+///
+/// As with [TakeWhile](crate::primitive::TakeWhile), the end of whitespace is only decided when a non-whitespace item is fed or upon [ParserCore::finalize]. Skipped input is not retained.
+pub struct Whitespace<B>
+where
+    B: ?Sized,
+{
+    nonempty: bool,
+    seen: bool,
+    phantom: PhantomData<B>,
+}
+
+impl<B> Whitespace<B>
+where
+    B: ?Sized,
+{
+    fn new(nonempty: bool) -> Self {
+        Whitespace {
+            nonempty,
+            seen: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B> Clone for Whitespace<B>
+where
+    B: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Whitespace {
+            nonempty: self.nonempty,
+            seen: self.seen,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B> fmt::Debug for Whitespace<B>
+where
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Whitespace")
+            .field("nonempty", &self.nonempty)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<B> ParserCore<B> for Whitespace<B>
+where
+    B: ?Sized + ItemBuf,
+    B::Item: TextItem,
+{
+    type Output = ();
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let consumed = buffer.prefix_len(|item| item.is_whitespace());
+        self.seen |= consumed > 0;
+
+        if consumed == buffer.len() {
+            Ok(Update {
+                consumed,
+                outcome: Next(self),
+            })
+        } else if self.nonempty && !self.seen {
+            Err(UnexpectedInput)
+        } else {
+            Ok(Update {
+                consumed,
+                outcome: Parsed(()),
+            })
+        }
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match self.feed(buffer)?.outcome {
+            Parsed(()) => Ok(Some(())),
+            Next(ws) if ws.nonempty && !ws.seen => Err(ExpectedMoreInput),
+            Next(_) => Ok(Some(())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,50 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ParserCore, Update};
+use crate::primitive::text::{whitespace0, whitespace1};
+
+#[test_case(" \t\nx" => Ok(Update { consumed: 3, outcome: Parsed(()) }) ; "some")]
+#[test_case("x" => Ok(Update { consumed: 0, outcome: Parsed(()) }) ; "none")]
+#[test_case("  " => Ok(Update { consumed: 2, outcome: Next(()) }) ; "undecided")]
+#[test_case("\u{3000}x" => Ok(Update { consumed: 3, outcome: Parsed(()) }) ; "unicode")]
+fn whitespace0_str(input: &str) -> ParseResult<Update<(), ()>, Infallible> {
+    whitespace0().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(b" x" => Ok(Update { consumed: 1, outcome: Parsed(()) }) ; "some")]
+#[test_case(b"x" => Err(UnexpectedInput) ; "none")]
+#[test_case(b"" => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn whitespace1_bytes(input: &[u8]) -> ParseResult<Update<(), ()>, Infallible> {
+    whitespace1().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case("", false => Ok(Some(())) ; "empty0")]
+#[test_case("", true => Err(ExpectedMoreInput) ; "empty1")]
+#[test_case(" ", true => Ok(Some(())) ; "space1")]
+fn whitespace_finalize(input: &str, nonempty: bool) -> ParseResult<Option<()>, Infallible> {
+    if nonempty {
+        whitespace1().finalize(input)
+    } else {
+        whitespace0().finalize(input)
+    }
+}
+
+#[test]
+fn whitespace1_across_feeds() {
+    let Update { outcome, .. } = whitespace1().feed("  ").unwrap();
+    let Next(ws) = outcome else {
+        panic!("expected Next");
+    };
+    assert!(matches!(
+        ws.feed("x"),
+        Ok(Update {
+            consumed: 0,
+            outcome: Parsed(())
+        })
+    ));
+}
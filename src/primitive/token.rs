@@ -0,0 +1,106 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+
+/// Construct a [Select] parser over `[T]` which yields the single token `f` maps to `Some` output, such as an enum variant's payload
+pub fn select<T, F, O>(f: F) -> Select<T, F>
+where
+    F: FnMut(&T) -> Option<O>,
+{
+    Select {
+        f,
+        phantom: PhantomData,
+    }
+}
+
+/// Construct a [Select] parser over `[T]` which yields a single token equal to `value`
+pub fn token<T>(value: T) -> Select<T, impl Clone + FnMut(&T) -> Option<T>>
+where
+    T: Clone + PartialEq,
+{
+    select(move |t: &T| (t == &value).then(|| t.clone()))
+}
+
+/// Construct a [Select] parser over `[T]` which yields a single token of the same enum variant as `kind`, ignoring its payload
+pub fn token_kind<T>(kind: &T) -> Select<T, impl Clone + FnMut(&T) -> Option<T>>
+where
+    T: Clone,
+{
+    let kind = mem::discriminant(kind);
+    select(move |t: &T| (mem::discriminant(t) == kind).then(|| t.clone()))
+}
+
+/// Construct a [Select] parser over `[T]` which yields a single token satisfying `pred`, such as an enum variant
+pub fn satisfy_token<T, F>(mut pred: F) -> Select<T, impl Clone + FnMut(&T) -> Option<T>>
+where
+    T: Clone,
+    F: Clone + FnMut(&T) -> bool,
+{
+    select(move |t: &T| pred(t).then(|| t.clone()))
+}
+
+/// A [Select] parser matches a single token of `[T]` input, yielding an owned output
+///
+/// Unlike [Literal](crate::primitive::Literal) over `[T]`, which yields a borrowed slice, outputs do not borrow from the input, so they may be retained across calls to [ParserCore::feed].
+///
+/// A [Select] places no bounds on `T` itself, but combinators over `[T]`, such as [Or](crate::combinator::Or) and [Then](crate::combinator::Then), require `[T]:` [BufRef](crate::buffer::BufRef), which holds for `T: Clone + PartialEq`.
+pub struct Select<T, F> {
+    f: F,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, F> Clone for Select<T, F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Select {
+            f: self.f.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> fmt::Debug for Select<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select").field("f", &"...").finish()
+    }
+}
+
+impl<T, F, O> ParserCore<[T]> for Select<T, F>
+where
+    F: FnMut(&T) -> Option<O>,
+{
+    type Output = O;
+    type Error = Infallible;
+
+    fn feed(mut self, buffer: &[T]) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        match buffer.first() {
+            None => Ok(Update {
+                consumed: 0,
+                outcome: Next(self),
+            }),
+            Some(t) => (self.f)(t)
+                .map(|output| Update {
+                    consumed: 1,
+                    outcome: Parsed(output),
+                })
+                .ok_or(UnexpectedInput),
+        }
+    }
+
+    fn finalize(mut self, buffer: &[T]) -> ParseResult<Option<Self::Output>, Self::Error> {
+        let t = buffer.first().ok_or(ExpectedMoreInput)?;
+        (self.f)(t).map(Some).ok_or(UnexpectedInput)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+use std::convert::Infallible;
+
+use either::Either;
+use test_case::test_case;
+
+use crate::combinator::recursive;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::expression::Associativity::Left;
+use crate::expression::Expression;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ParserCore, PushParser, Update};
+use crate::primitive::text::lexeme;
+use crate::primitive::{one_of, satisfy_token, select, token, token_kind, uint};
+use crate::sequence::SequenceParser;
+
+use self::Tok::{Ident, Num, Plus, Times};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Num(u32),
+    Ident(String),
+    Plus,
+    Times,
+}
+
+fn num() -> impl ParserCore<[Tok], Output = u32, Error = Infallible> + Clone {
+    select(|t: &Tok| match t {
+        Num(n) => Some(*n),
+        _ => None,
+    })
+}
+
+#[test_case(&[Plus, Num(1)] => Ok(Update { consumed: 1, outcome: Parsed(Plus) }) ; "matches")]
+#[test_case(&[Times] => Err(UnexpectedInput) ; "mismatch")]
+#[test_case(&[] => Ok(Update { consumed: 0, outcome: Next(()) }) ; "empty")]
+fn token_plus(input: &[Tok]) -> ParseResult<Update<(), Tok>, Infallible> {
+    token(Plus).feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(&[Num(7)] => Ok(Some(7)) ; "payload")]
+#[test_case(&[Ident("x".to_string())] => Err(UnexpectedInput) ; "other_variant")]
+#[test_case(&[] => Err(ExpectedMoreInput) ; "empty")]
+fn select_num(input: &[Tok]) -> ParseResult<Option<u32>, Infallible> {
+    num().finalize(input)
+}
+
+#[test_case(&[Ident("x".to_string())] => Ok(Some(Ident("x".to_string()))) ; "ident")]
+#[test_case(&[Num(7)] => Err(UnexpectedInput) ; "num")]
+fn satisfy_ident(input: &[Tok]) -> ParseResult<Option<Tok>, Infallible> {
+    satisfy_token(|t: &Tok| matches!(t, Ident(_))).finalize(input)
+}
+
+#[test_case(&[Ident("y".to_string())] => Ok(Some(Ident("y".to_string()))) ; "same_variant")]
+#[test_case(&[Plus] => Err(UnexpectedInput) ; "other_variant")]
+fn token_kind_ident(input: &[Tok]) -> ParseResult<Option<Tok>, Infallible> {
+    token_kind(&Ident(String::new())).finalize(input)
+}
+
+/// A token type without [PartialEq], which [select] and [token_kind] accept
+#[derive(Clone, Debug)]
+enum Bare {
+    Word(&'static str),
+    Comma,
+}
+
+#[test]
+fn select_without_partial_eq() {
+    let word = select(|t: &Bare| match t {
+        Bare::Word(w) => Some(*w),
+        Bare::Comma => None,
+    });
+    assert_eq!(word.finalize(&[Bare::Word("a")]), Ok(Some("a")));
+
+    let comma = token_kind(&Bare::Comma).feed(&[Bare::Comma, Bare::Word("b")]);
+    assert!(matches!(
+        comma,
+        Ok(Update {
+            consumed: 1,
+            outcome: Parsed(Bare::Comma)
+        })
+    ));
+}
+
+#[test]
+fn owned_outputs_outlive_input() {
+    let parser = satisfy_token(|t: &Tok| matches!(t, Ident(_)))
+        .repeated()
+        .collect::<Vec<_>>();
+
+    let input = vec![Ident("a".to_string()), Ident("b".to_string())];
+    let Update { consumed, outcome } = parser.feed(input.as_slice()).unwrap();
+    drop(input);
+
+    assert_eq!(consumed, 2);
+    let Next(parser) = outcome else {
+        panic!("more identifiers may follow");
+    };
+    assert_eq!(
+        parser.finalize(&[]),
+        Ok(Some(vec![Ident("a".to_string()), Ident("b".to_string())]))
+    );
+}
+
+#[test]
+fn expression_over_tokens() {
+    let lexer = recursive(|_| {
+        lexeme(uint::<u32, _>())
+            .map_output(Num)
+            .or(lexeme(one_of("+*")).map_output(|c| if c == '+' { Plus } else { Times }))
+            .map_output(Either::into_inner)
+    })
+    .repeated();
+    let sum = Expression::new(num())
+        .infix(token(Plus), 1, Left, |l, _, r| l + r)
+        .infix(token(Times), 2, Left, |l, _, r| l * r);
+
+    assert_eq!(lexer.pipe(sum).finalize("1 + 2 * 3 + 4"), Ok(Some(11)));
+}
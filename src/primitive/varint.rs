@@ -0,0 +1,209 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput};
+use crate::error::ParseResult;
+use crate::parser::{ParserCore, Update};
+use crate::primitive::Integer;
+
+/// Construct a [Leb128] parser which yields an unsigned LEB128 integer, also known as a protobuf varint
+pub fn uleb128<T>() -> Leb128<T>
+where
+    T: Integer + TryFrom<u128> + TryFrom<i128>,
+{
+    Leb128::new(Encoding::Unsigned)
+}
+
+/// Construct a [Leb128] parser which yields a signed LEB128 integer, as in WebAssembly and DWARF
+pub fn sleb128<T>() -> Leb128<T>
+where
+    T: Integer + TryFrom<u128> + TryFrom<i128>,
+{
+    Leb128::new(Encoding::Signed)
+}
+
+/// Construct a [Leb128] parser which yields a zigzag-encoded signed integer, as in protobuf `sint32` and `sint64`
+pub fn zigzag<T>() -> Leb128<T>
+where
+    T: Integer + TryFrom<u128> + TryFrom<i128>,
+{
+    Leb128::new(Encoding::Zigzag)
+}
+
+/// Errors from decoding variable-length integers
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum VarintError {
+    /// The encoded value does not fit in the output type
+    #[error("varint overflows its type")]
+    Overflow,
+
+    /// The encoding has redundant trailing bytes
+    #[error("overlong varint encoding")]
+    Overlong,
+}
+
+/// A [Leb128] parser decodes a little-endian base 128 variable-length integer from `[u8]` input
+///
+/// Each byte carries 7 bits of payload, least significant first, with the high bit set on all but the final byte. Bytes are decoded as they arrive, so a value may straddle any number of buffers.
+///
+/// Encodings longer than the output type needs, or which end with a byte carrying only zero or sign-extension bits, are reported as [VarintError::Overlong], while values outside the output type's range are reported as [VarintError::Overflow].
+pub struct Leb128<T> {
+    encoding: Encoding,
+    value: u128,
+    shift: u32,
+    last: u8,
+    phantom: PhantomData<T>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Encoding {
+    Unsigned,
+    Signed,
+    Zigzag,
+}
+
+impl<T> Leb128<T>
+where
+    T: Integer + TryFrom<u128> + TryFrom<i128>,
+{
+    fn new(encoding: Encoding) -> Self {
+        Leb128 {
+            encoding,
+            value: 0,
+            shift: 0,
+            last: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The most bytes a value of `T` may occupy
+    fn max_len() -> u32 {
+        (std::mem::size_of::<T>() as u32 * 8).div_ceil(7)
+    }
+
+    /// The payload of a byte which adds no information after the previous byte
+    fn fill(&self) -> u8 {
+        if self.encoding == Encoding::Signed && self.last & 0x40 != 0 {
+            0x7f
+        } else {
+            0
+        }
+    }
+
+    fn push(&mut self, payload: u8) -> Result<(), VarintError> {
+        // Near the top of `u128`, only some payload bits fit:
+        if let Some(avail) = 128u32.checked_sub(self.shift).filter(|&avail| avail < 7) {
+            let fits = match self.encoding {
+                Encoding::Signed => {
+                    let extended = ((payload << 1) as i8) >> 1;
+                    matches!(extended >> (avail - 1), 0 | -1)
+                }
+                _ => payload >> avail == 0,
+            };
+            if !fits {
+                return Err(VarintError::Overflow);
+            }
+        }
+
+        self.value |= u128::from(payload) << self.shift;
+        Ok(())
+    }
+
+    fn output(self, payload: u8) -> Result<T, VarintError> {
+        let end = self.shift + 7;
+        match self.encoding {
+            Encoding::Unsigned => T::try_from(self.value).ok(),
+            Encoding::Signed => {
+                let mut value = self.value;
+                if end < 128 && payload & 0x40 != 0 {
+                    value |= !0 << end;
+                }
+                T::try_from(value as i128).ok()
+            }
+            Encoding::Zigzag => {
+                let magnitude = (self.value >> 1) as i128;
+                let sign = -((self.value & 1) as i128);
+                T::try_from(magnitude ^ sign).ok()
+            }
+        }
+        .ok_or(VarintError::Overflow)
+    }
+}
+
+impl<T> Clone for Leb128<T> {
+    fn clone(&self) -> Self {
+        Leb128 {
+            encoding: self.encoding,
+            value: self.value,
+            shift: self.shift,
+            last: self.last,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Leb128<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Leb128")
+            .field("encoding", &self.encoding)
+            .field("value", &self.value)
+            .field("shift", &self.shift)
+            .finish()
+    }
+}
+
+impl<T> ParserCore<[u8]> for Leb128<T>
+where
+    T: Integer + TryFrom<u128> + TryFrom<i128>,
+{
+    type Output = T;
+    type Error = VarintError;
+
+    fn feed(mut self, buffer: &[u8]) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        for (i, &byte) in buffer.iter().enumerate() {
+            let payload = byte & 0x7f;
+            let redundant = self.shift > 0 && payload == self.fill();
+
+            if self.shift / 7 >= Self::max_len() {
+                return Err(Custom(if redundant {
+                    VarintError::Overlong
+                } else {
+                    VarintError::Overflow
+                }));
+            }
+            self.push(payload)?;
+
+            if byte & 0x80 == 0 {
+                if redundant {
+                    return Err(Custom(VarintError::Overlong));
+                }
+                return Ok(Update {
+                    consumed: i + 1,
+                    outcome: Parsed(self.output(payload)?),
+                });
+            }
+
+            self.last = byte;
+            self.shift += 7;
+        }
+
+        Ok(Update {
+            consumed: buffer.len(),
+            outcome: Next(self),
+        })
+    }
+
+    fn finalize(self, buffer: &[u8]) -> ParseResult<Option<Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        match self.feed(buffer)?.outcome {
+            Parsed(value) => Ok(Some(value)),
+            _ => Err(ExpectedMoreInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use std::convert::Infallible;
+
+use test_case::test_case;
+
+use crate::error::ParseError::{Custom, ExpectedMoreInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::{Next, Parsed};
+use crate::parser::{ByteParser, ParserCore, PushParser, Update};
+use crate::primitive::{sleb128, take, uleb128, zigzag, VarintError};
+
+#[test_case(&[0x00] => Ok(Some(0)) ; "zero")]
+#[test_case(&[0xe5, 0x8e, 0x26] => Ok(Some(624485)) ; "multibyte")]
+#[test_case(&[0xff, 0xff, 0xff, 0xff, 0x0f] => Ok(Some(u32::MAX)) ; "max")]
+#[test_case(&[0xff, 0xff, 0xff, 0xff, 0x1f] => Err(Custom(VarintError::Overflow)) ; "overflow")]
+#[test_case(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x01] => Err(Custom(VarintError::Overflow)) ; "too_long")]
+#[test_case(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00] => Err(Custom(VarintError::Overlong)) ; "too_long_zero")]
+#[test_case(&[0x81, 0x00] => Err(Custom(VarintError::Overlong)) ; "overlong")]
+#[test_case(&[0x81] => Err(ExpectedMoreInput) ; "truncated")]
+fn uleb128_u32(input: &[u8]) -> ParseResult<Option<u32>, VarintError> {
+    uleb128::<u32>().finalize(input)
+}
+
+#[test_case(&[0x7f] => Ok(Some(-1)) ; "minus_one")]
+#[test_case(&[0x3f] => Ok(Some(63)) ; "positive_one_byte")]
+#[test_case(&[0xc0, 0x00] => Ok(Some(64)) ; "positive_needs_sign_byte")]
+#[test_case(&[0xc0, 0xbb, 0x78] => Ok(Some(-123456)) ; "negative")]
+#[test_case(&[0x80, 0x80, 0x80, 0x80, 0x78] => Ok(Some(i32::MIN)) ; "min")]
+#[test_case(&[0x80, 0x80, 0x80, 0x80, 0x70] => Err(Custom(VarintError::Overflow)) ; "underflow")]
+#[test_case(&[0xff, 0x7f] => Err(Custom(VarintError::Overlong)) ; "overlong_negative")]
+#[test_case(&[0x80, 0x00] => Err(Custom(VarintError::Overlong)) ; "overlong_positive")]
+fn sleb128_i32(input: &[u8]) -> ParseResult<Option<i32>, VarintError> {
+    sleb128::<i32>().finalize(input)
+}
+
+#[test_case(&[0x00] => Ok(Some(0)) ; "zero")]
+#[test_case(&[0x01] => Ok(Some(-1)) ; "minus_one")]
+#[test_case(&[0x02] => Ok(Some(1)) ; "one")]
+#[test_case(&[0x03] => Ok(Some(-2)) ; "minus_two")]
+#[test_case(&[0xfe, 0xff, 0xff, 0xff, 0x0f] => Ok(Some(i32::MAX)) ; "max")]
+#[test_case(&[0xff, 0xff, 0xff, 0xff, 0x0f] => Ok(Some(i32::MIN)) ; "min")]
+#[test_case(&[0x80, 0x80, 0x80, 0x80, 0x10] => Err(Custom(VarintError::Overflow)) ; "overflow")]
+fn zigzag_i32(input: &[u8]) -> ParseResult<Option<i32>, VarintError> {
+    zigzag::<i32>().finalize(input)
+}
+
+#[test]
+fn u128_extremes() {
+    let mut max = vec![0xff; 18];
+    max.push(0x03);
+    assert_eq!(uleb128::<u128>().finalize(&max), Ok(Some(u128::MAX)));
+
+    let mut min = vec![0x80; 18];
+    min.push(0x7e);
+    assert_eq!(sleb128::<i128>().finalize(&min), Ok(Some(i128::MIN)));
+
+    let mut over = vec![0xff; 18];
+    over.push(0x07);
+    assert_eq!(
+        uleb128::<u128>().finalize(&over),
+        Err(Custom(VarintError::Overflow))
+    );
+}
+
+#[test_case(&[0xe5, 0x8e, 0x26, 0xff] => Ok(Update { consumed: 3, outcome: Parsed(624485) }) ; "trailing")]
+#[test_case(&[0xe5, 0x8e] => Ok(Update { consumed: 2, outcome: Next(()) }) ; "split")]
+fn uleb128_feed(input: &[u8]) -> ParseResult<Update<(), u64>, VarintError> {
+    uleb128::<u64>().feed(input).map(|up| up.map_next(|_| ()))
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn length_prefixed(bufsize: usize) {
+    let mut input = vec![0x82, 0x01];
+    input.extend(std::iter::repeat_n(b'x', 130));
+    input.extend_from_slice(&[0x03, b'e', b'n', b'd']);
+
+    let (body, tail) = uleb128::<usize>()
+        .and_then(take)
+        .then(uleb128::<usize>().and_then(take))
+        .parse_reader_with_bufsize::<_, Infallible>(input.as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(body, vec![b'x'; 130]);
+    assert_eq!(tail, b"end");
+}
@@ -0,0 +1,102 @@
+use std::any::Any;
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::buffer::BufRef;
+use crate::combinator::require_state;
+use crate::error::ParseResult;
+use crate::parser::{Context, ParserCore, Update};
+
+/// Construct a [WithState] parser which yields `f` applied to the per-parse user state
+pub fn with_state<S, F, T>(f: F) -> WithState<S, F>
+where
+    S: Any,
+    F: FnOnce(&mut S) -> T,
+{
+    WithState {
+        f,
+        phantom: PhantomData,
+    }
+}
+
+/// A [WithState] parser yields the result of `F` applied to the per-parse user state `S`, without consuming input
+///
+/// This is useful to inspect the state, such as to choose a parser with [and_then](crate::parser::PushParser::and_then) based on the current indentation level, or to update it between other parsers.
+///
+/// Parsing fails with [ParseError::MissingState](crate::error::ParseError::MissingState) if the [Context] has no user state of type `S`, as when driven by [ParserCore::feed] rather than a driver which accepts state.
+pub struct WithState<S, F> {
+    f: F,
+    phantom: PhantomData<fn(&mut S)>,
+}
+
+impl<S, F> Clone for WithState<S, F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        WithState {
+            f: self.f.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, F> fmt::Debug for WithState<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithState").field("f", &"...").finish()
+    }
+}
+
+impl<B, S, F, T> ParserCore<B> for WithState<S, F>
+where
+    B: ?Sized,
+    S: Any,
+    F: FnOnce(&mut S) -> T,
+{
+    type Output = T;
+    type Error = Infallible;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::Parsed;
+
+        Ok(Update {
+            consumed: 0,
+            outcome: Parsed((self.f)(require_state(&mut ctx)?)),
+        })
+    }
+
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        Ok(Some((self.f)(require_state(&mut ctx)?)))
+    }
+
+    fn finalize_consumed(
+        self,
+        mut ctx: Context<'_>,
+        _: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        Ok(Some((0, (self.f)(require_state(&mut ctx)?))))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use test_case::test_case;
+
+use crate::error::ParseError::MissingState;
+use crate::parser::{ByteParser, ParserCore, PushParser};
+use crate::primitive::{take, with_state};
+
+#[test_case(3 => b"abc".to_vec() ; "three")]
+#[test_case(0 => Vec::<u8>::new() ; "zero")]
+fn width_from_state(mut width: usize) -> Vec<u8> {
+    with_state(|w: &mut usize| *w)
+        .and_then(take)
+        .parse_reader_with_bufsize_and_state(b"abcdef".as_slice(), 1, &mut width)
+        .unwrap()
+}
+
+#[test]
+fn update_between_parsers() {
+    let mut depth = 0u32;
+    let ((before, _), after) = with_state(|d: &mut u32| {
+        *d += 1;
+        *d
+    })
+    .then(take(2))
+    .then(with_state(|d: &mut u32| *d * 10))
+    .parse_reader_with_state(b"ab".as_slice(), &mut depth)
+    .unwrap();
+
+    assert_eq!((before, after, depth), (1, 10, 1));
+}
+
+#[test]
+fn missing_state() {
+    let result = with_state(|w: &mut usize| *w).finalize(b"".as_slice());
+
+    assert!(matches!(result, Err(MissingState("usize"))));
+}
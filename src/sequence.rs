@@ -1,8 +1,10 @@
-//! [SequenceParser] supports parsing sequences of items
+//! [SequenceParser] supports parsing sequences of items, including lexing tokens for a token parser with [Pipe]
 mod collect;
 mod foldl;
+mod pipe;
 mod seqparser;
 
 pub use self::collect::Collect;
 pub use self::foldl::Foldl;
+pub use self::pipe::Pipe;
 pub use self::seqparser::SequenceParser;
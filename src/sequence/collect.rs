@@ -1,10 +1,10 @@
 use crate::buffer::BufRef;
 use crate::error::ParseResult;
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 use crate::sequence::SequenceParser;
 
 /// Collect items emitted from [SequenceParser] `P` into container `C`
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Collect<P, C> {
     repeated: P,
     collection: C,
@@ -32,6 +32,18 @@ where
     type Error = P::Error;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::{Next, Parsed};
 
         let Collect {
@@ -40,7 +52,7 @@ where
         } = self;
 
         repeated
-            .feed(buffer)
+            .feed_with(ctx.reborrow(), buffer)
             .and_then(|Update { consumed, outcome }| match outcome {
                 Next(repeated) => Ok(Update {
                     consumed,
@@ -59,7 +71,8 @@ where
                         repeated,
                         collection,
                     };
-                    let subup = intermediate.feed(buffer.drop_up_to(consumed))?;
+                    let subup = intermediate
+                        .feed_with(ctx.advance(consumed), buffer.drop_up_to(consumed))?;
                     Ok(Update {
                         consumed: consumed + subup.consumed,
                         outcome: subup.outcome,
@@ -68,20 +81,24 @@ where
             })
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
         let Collect {
             repeated,
             mut collection,
         } = self;
 
-        if let Some(output) = repeated.finalize(buffer)? {
+        if let Some(output) = repeated.finalize_with(ctx.reborrow(), buffer)? {
             if let Some((repeated, item)) = output {
                 collection.extend_one(item);
                 let nextself = Collect {
                     repeated,
                     collection,
                 };
-                nextself.finalize(buffer.drop_up_to(buffer.len()))
+                nextself.finalize_with(ctx.advance(buffer.len()), buffer.drop_up_to(buffer.len()))
             } else {
                 Ok(Some(collection))
             }
@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 
 use crate::buffer::BufRef;
 use crate::error::ParseResult;
-use crate::parser::{ParserCore, Update};
+use crate::parser::{Context, ParserCore, Update};
 use crate::sequence::SequenceParser;
 
 /// Convert a [SequenceParser] into a parser that folds each item into a final output
@@ -60,12 +60,24 @@ where
     type Error = S::Error;
 
     fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
         use crate::parser::Outcome::{Next, Parsed};
 
         let Foldl { parser, acc, f, .. } = self;
 
         parser
-            .feed(buffer)
+            .feed_with(ctx.reborrow(), buffer)
             .and_then(|Update { consumed, outcome }| match outcome {
                 Next(parser) => Ok(Update {
                     consumed,
@@ -77,7 +89,8 @@ where
                 }),
                 Parsed(Some((parser, x))) => {
                     let intermediate = Foldl::new(parser, f(acc, x), f);
-                    let subup = intermediate.feed(buffer.drop_up_to(consumed))?;
+                    let subup = intermediate
+                        .feed_with(ctx.advance(consumed), buffer.drop_up_to(consumed))?;
                     Ok(Update {
                         consumed: consumed + subup.consumed,
                         outcome: subup.outcome,
@@ -86,11 +99,16 @@ where
             })
     }
 
-    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+    fn finalize_with(
+        self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
         let Foldl { parser, acc, f, .. } = self;
 
-        if let Some(Some((p, x))) = parser.finalize(buffer)? {
-            return Foldl::new(p, f(acc, x), f).finalize(buffer.drop_up_to(buffer.len()));
+        if let Some(Some((p, x))) = parser.finalize_with(ctx.reborrow(), buffer)? {
+            return Foldl::new(p, f(acc, x), f)
+                .finalize_with(ctx.advance(buffer.len()), buffer.drop_up_to(buffer.len()));
         }
         Ok(Some(acc))
     }
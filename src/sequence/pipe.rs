@@ -0,0 +1,170 @@
+use either::Either::{self, Left, Right};
+
+use crate::buffer::BufRef;
+use crate::error::ParseError::ExpectedMoreInput;
+use crate::error::{ParseResult, ParseResultExt};
+use crate::parser::{Context, Downstream, ParserCore, Update};
+use crate::sequence::SequenceParser;
+
+/// Chain a lexer [SequenceParser] of tokens `T` into a parser `P` over `[T]`
+///
+/// Tokens are buffered internally until `P` consumes them, so the [Pipe] is a single parser over the lexer's input, such as `[u8]` for use with [ByteParser::parse_reader](crate::parser::ByteParser::parse_reader). The token stream ends when the lexer ends, at which point `P` is finalized, and any input the lexer did not match remains unconsumed. The input of tokens which `P` does not consume also remains unconsumed, so the lexer may see it again.
+///
+/// `P` observes positions in the token stream via [Context::offset] rather than positions in the lexer's input.
+#[derive(Clone, Debug)]
+pub struct Pipe<L, P, T> {
+    lexer: Option<L>,
+    downstream: Downstream<P, T>,
+}
+
+impl<L, P, T> Pipe<L, P, T> {
+    /// Create a new pipe from `lexer` into `parser`
+    pub fn new(lexer: L, parser: P) -> Self {
+        Pipe {
+            lexer: Some(lexer),
+            downstream: Downstream::new(parser),
+        }
+    }
+
+    /// Lex as much of `buffer` as possible after the input already lexed, returning whether any tokens were lexed
+    fn lex<B>(&mut self, mut ctx: Context<'_>, buffer: &B) -> ParseResult<bool, L::Error>
+    where
+        B: ?Sized + BufRef,
+        L: SequenceParser<B, Item = T>,
+    {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let mut lexed = false;
+        while let Some(lexer) = self.lexer.take() {
+            let start = self.downstream.lookahead();
+            let Update { consumed, outcome } =
+                lexer.feed_with(ctx.advance(start), buffer.drop_up_to(start))?;
+            self.downstream.advance(consumed);
+
+            match outcome {
+                Next(lexer) => {
+                    self.lexer = Some(lexer);
+                    break;
+                }
+                Parsed(Some((lexer, token))) => {
+                    self.downstream.push(token);
+                    self.lexer = Some(lexer);
+                    lexed = true;
+                }
+                Parsed(None) => self.downstream.mark(),
+            }
+        }
+        Ok(lexed)
+    }
+
+    /// Finalize the lexer on the rest of `buffer`, lexing tokens until it ends or stops consuming input, and returning whether any tokens were lexed
+    fn lex_final<B>(&mut self, mut ctx: Context<'_>, buffer: &B) -> ParseResult<bool, L::Error>
+    where
+        B: ?Sized + BufRef,
+        L: SequenceParser<B, Item = T>,
+    {
+        let mut lexed = false;
+        while let Some(lexer) = self.lexer.take() {
+            let start = self.downstream.lookahead();
+            match lexer.finalize_consumed(ctx.advance(start), buffer.drop_up_to(start))? {
+                Some((consumed, Some((lexer, token)))) => {
+                    self.downstream.advance(consumed);
+                    self.downstream.push(token);
+                    lexed = true;
+                    if consumed > 0 {
+                        self.lexer = Some(lexer);
+                    }
+                }
+                Some((consumed, None)) => {
+                    self.downstream.advance(consumed);
+                    self.downstream.mark();
+                }
+                None => {}
+            }
+        }
+        Ok(lexed)
+    }
+}
+
+impl<L, P, T, B> ParserCore<B> for Pipe<L, P, T>
+where
+    B: ?Sized + BufRef,
+    L: SequenceParser<B, Item = T>,
+    P: ParserCore<[T]>,
+    [T]: BufRef,
+{
+    type Output = P::Output;
+    type Error = Either<L::Error, P::Error>;
+
+    fn feed(self, buffer: &B) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        self.feed_with(Context::default(), buffer)
+    }
+
+    fn finalize(self, buffer: &B) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_with(Context::default(), buffer)
+    }
+
+    fn feed_with(
+        mut self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Update<Self, Self::Output>, Self::Error> {
+        use crate::parser::Outcome::{Next, Parsed};
+
+        let lexed = self.lex(ctx.reborrow(), buffer).map_err_custom(Left)?;
+
+        if self.lexer.is_none() {
+            // The token stream has ended:
+            let (consumed, output) = self
+                .downstream
+                .finish(ctx)
+                .map_err_custom(Right)?
+                .ok_or(ExpectedMoreInput)?;
+            return Ok(Update {
+                consumed,
+                outcome: Parsed(output),
+            });
+        }
+
+        if !lexed {
+            // The parser has no new tokens to see:
+            return Ok(Update {
+                consumed: 0,
+                outcome: Next(self),
+            });
+        }
+
+        let Pipe { lexer, downstream } = self;
+        downstream
+            .feed(ctx)
+            .map_err_custom(Right)
+            .map(|up| up.map_next(|downstream| Pipe { lexer, downstream }))
+    }
+
+    fn finalize_with(
+        self,
+        ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<Self::Output>, Self::Error> {
+        self.finalize_consumed(ctx, buffer)
+            .map(|optout| optout.map(|(_, output)| output))
+    }
+
+    fn finalize_consumed(
+        mut self,
+        mut ctx: Context<'_>,
+        buffer: &B,
+    ) -> ParseResult<Option<(usize, Self::Output)>, Self::Error>
+    where
+        B: BufRef,
+    {
+        self.lex(ctx.reborrow(), buffer).map_err_custom(Left)?;
+        self.lex_final(ctx.reborrow(), buffer)
+            .map_err_custom(Left)?;
+
+        self.downstream.finish(ctx).map_err_custom(Right)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,143 @@
+use std::convert::Infallible;
+
+use either::Either;
+use test_case::test_case;
+
+use crate::combinator::recursive;
+use crate::error::ParseError::{ExpectedMoreInput, UnexpectedInput};
+use crate::error::ParseResult;
+use crate::parser::Outcome::Parsed;
+use crate::parser::{ByteParser, ParserCore, PushParser, TextParser, Update};
+use crate::primitive::text::lexeme;
+use crate::primitive::{any, end, literal, one_of, position, uint};
+use crate::sequence::SequenceParser;
+
+use self::Tok::{Num, Plus, Times};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Num(u32),
+    Plus,
+    Times,
+}
+
+/// Lex numbers and operators separated by optional whitespace
+fn lexer() -> impl SequenceParser<str, Item = Tok, Error = Infallible> + Clone {
+    recursive(|_| {
+        lexeme(uint::<u32, _>())
+            .map_output(Num)
+            .or(lexeme(one_of("+*")).map_output(|c| if c == '+' { Plus } else { Times }))
+            .map_output(Either::into_inner)
+    })
+    .repeated()
+}
+
+fn tokens() -> impl ParserCore<[Tok], Output = Vec<Tok>, Error = Infallible> + Clone {
+    any().repeated().collect()
+}
+
+#[test_case("1 + 2*3" => Ok(Some(vec![Num(1), Plus, Num(2), Times, Num(3)])) ; "tokens")]
+#[test_case("" => Ok(Some(vec![])) ; "empty")]
+#[test_case("1 + x" => Ok(Some(vec![Num(1), Plus])) ; "lexer_ends_early")]
+fn finalize(input: &str) -> ParseResult<Option<Vec<Tok>>, Either<Infallible, Infallible>> {
+    lexer().pipe(tokens()).finalize(input)
+}
+
+#[test_case("+1" => Ok(Some(Plus)) ; "matches")]
+#[test_case("1+" => Err(UnexpectedInput) ; "mismatch")]
+#[test_case("" => Err(ExpectedMoreInput) ; "no_tokens")]
+fn finalize_parser_error(input: &str) -> ParseResult<Option<Tok>, Either<Infallible, Infallible>> {
+    lexer()
+        .pipe(literal([Plus].as_slice()).map_output(|toks| toks[0].clone()))
+        .finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+#[test_case(1<<14)]
+fn split_feeds(bufsize: usize) {
+    let output = lexer()
+        .pipe(tokens())
+        .boxed()
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(b"12 + 345 * 6 * 78".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(
+        output,
+        vec![Num(12), Plus, Num(345), Times, Num(6), Times, Num(78)]
+    );
+}
+
+#[test]
+fn parser_ends_before_other_tokens() {
+    let Update { consumed, outcome } = lexer().pipe(any()).feed("1 + 2").unwrap();
+
+    assert_eq!(consumed, 2);
+    assert!(matches!(outcome, Parsed(Num(1))));
+}
+
+type ThenEndError = Either<Either<Infallible, Infallible>, Infallible>;
+
+#[test_case("1 " => Ok(Some(Num(1))) ; "single_token")]
+#[test_case("1 + 2" => Err(UnexpectedInput) ; "leftover_tokens")]
+fn then_end(input: &str) -> ParseResult<Option<Tok>, ThenEndError> {
+    lexer()
+        .pipe(any())
+        .then(end())
+        .map_output(|(tok, _)| tok)
+        .finalize(input)
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(1<<14)]
+fn resumes_after_unconsumed_tokens(bufsize: usize) {
+    let output = lexer()
+        .pipe(any().then(any()))
+        .then(lexer().pipe(tokens()))
+        .boxed()
+        .into_utf8_parser()
+        .parse_reader_with_bufsize::<_, Infallible>(b"1 + 2 * 3".as_slice(), bufsize)
+        .unwrap();
+
+    assert_eq!(output, ((Num(1), Plus), vec![Num(2), Times, Num(3)]));
+}
+
+/// Lex `'a'` tokens, each of which is held back at the end of input until `"aaab"` is ruled out
+fn held_lexer() -> impl SequenceParser<str, Item = char, Error = Infallible> + Clone {
+    literal("aaab")
+        .or(literal("a"))
+        .map_output(|_| 'a')
+        .repeated()
+}
+
+#[test_case("aaaa" => Ok(Some(vec!['a'; 4])) ; "several_held")]
+#[test_case("aaa!" => Ok(Some(vec!['a'; 3])) ; "unlexed_rest")]
+fn finalize_lexes_remaining_tokens(
+    input: &str,
+) -> ParseResult<Option<Vec<char>>, Either<Infallible, Infallible>> {
+    held_lexer()
+        .pipe(any().repeated().collect())
+        .finalize(input)
+}
+
+#[test]
+fn finalize_leaves_unlexed_input() {
+    let result = held_lexer()
+        .pipe(any().repeated().collect::<Vec<_>>())
+        .then(literal("!"))
+        .finalize("aaa!");
+
+    assert_eq!(result, Ok(Some((vec!['a'; 3], "!"))));
+}
+
+#[test]
+fn token_positions() {
+    let result = lexer()
+        .pipe(any().then(any()).then(position()))
+        .finalize("10 + 20");
+
+    assert_eq!(result, Ok(Some(((Num(10), Plus), 2))));
+}
@@ -1,6 +1,6 @@
 use crate::buffer::BufRef;
 use crate::parser::ParserCore;
-use crate::sequence::{Collect, Foldl};
+use crate::sequence::{Collect, Foldl, Pipe};
 
 /// A sequence parser produces an item and a continuation state for multiple items in a sequence
 pub trait SequenceParser<B>: ParserCore<B, Output = Option<(Self, Self::Item)>>
@@ -25,6 +25,15 @@ where
     {
         Collect::<_, C>::from(self)
     }
+
+    /// Feed each parsed item as a token to `parser` over `[Self::Item]`, yielding `P::Output`
+    fn pipe<P>(self, parser: P) -> Pipe<Self, P, Self::Item>
+    where
+        P: ParserCore<[Self::Item]>,
+        [Self::Item]: BufRef,
+    {
+        Pipe::new(self, parser)
+    }
 }
 
 impl<B, P, X> SequenceParser<B> for P